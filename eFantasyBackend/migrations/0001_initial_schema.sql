@@ -0,0 +1,31 @@
+-- Baseline schema for users and leagues.
+-- Uses IF NOT EXISTS so databases created before migrations were introduced
+-- can adopt the migration history without failing.
+
+CREATE TABLE IF NOT EXISTS users (
+    id BIGSERIAL PRIMARY KEY,
+    username TEXT NOT NULL UNIQUE,
+    email TEXT NOT NULL UNIQUE,
+    password TEXT NOT NULL,
+    nickname TEXT,
+    bio TEXT,
+    avatar_url TEXT,
+    wins INTEGER NOT NULL DEFAULT 0,
+    losses INTEGER NOT NULL DEFAULT 0,
+    ties INTEGER NOT NULL DEFAULT 0,
+    total_points DOUBLE PRECISION NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS leagues (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    admin_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    max_teams INTEGER NOT NULL,
+    is_public BOOLEAN NOT NULL DEFAULT TRUE,
+    draft_time TIMESTAMPTZ NOT NULL,
+    scoring_type TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,7 @@
+-- League membership and optional shared join password.
+
+ALTER TABLE leagues ADD COLUMN IF NOT EXISTS participants BIGINT[] NOT NULL DEFAULT '{}';
+ALTER TABLE leagues ADD COLUMN IF NOT EXISTS join_password TEXT;
+
+-- Existing leagues start with their admin as the only participant
+UPDATE leagues SET participants = ARRAY[admin_id] WHERE cardinality(participants) = 0;
@@ -0,0 +1,25 @@
+-- Emails are compared case-insensitively; store them lowercased and enforce uniqueness on that form.
+
+-- Accounts whose emails only differ by case or surrounding whitespace can't be merged automatically.
+-- Stop here and list them so they can be resolved by hand before the migration is re-run.
+DO $$
+DECLARE
+    duplicates TEXT;
+BEGIN
+    SELECT string_agg(normalized || ' (user ids ' || ids || ')', '; ')
+    INTO duplicates
+    FROM (
+        SELECT LOWER(TRIM(email)) AS normalized, string_agg(id::TEXT, ', ' ORDER BY id) AS ids
+        FROM users
+        GROUP BY LOWER(TRIM(email))
+        HAVING COUNT(*) > 1
+    ) AS clashes;
+
+    IF duplicates IS NOT NULL THEN
+        RAISE EXCEPTION 'Cannot normalize user emails, these accounts share an email: %', duplicates;
+    END IF;
+END $$;
+
+UPDATE users SET email = LOWER(TRIM(email)) WHERE email <> LOWER(TRIM(email));
+
+CREATE UNIQUE INDEX IF NOT EXISTS users_email_lower_idx ON users (LOWER(email));
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- IANA timezone name used for scheduling and day-grouped views.
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS timezone TEXT NOT NULL DEFAULT 'UTC';
@@ -0,0 +1,3 @@
+-- Lets admins freeze member actions while they fix a league.
+
+ALTER TABLE leagues ADD COLUMN IF NOT EXISTS locked BOOLEAN NOT NULL DEFAULT FALSE;
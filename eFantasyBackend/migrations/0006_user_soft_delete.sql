@@ -0,0 +1,3 @@
+-- Accounts closed by their owner are soft-deleted rather than removed.
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
@@ -0,0 +1,3 @@
+-- Tracks which password hashing settings produced each stored hash.
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS hash_version INTEGER NOT NULL DEFAULT 1;
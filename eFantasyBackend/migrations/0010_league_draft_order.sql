@@ -0,0 +1,3 @@
+-- Order in which participants pick during the draft; NULL until generated.
+
+ALTER TABLE leagues ADD COLUMN IF NOT EXISTS draft_order BIGINT[];
\ No newline at end of file
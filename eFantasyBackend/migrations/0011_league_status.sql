@@ -0,0 +1,7 @@
+-- Explicit league lifecycle state, replacing the status derived from draft_time.
+
+ALTER TABLE leagues ADD COLUMN IF NOT EXISTS status TEXT NOT NULL DEFAULT 'pending'
+    CHECK (status IN ('pending', 'drafting', 'active', 'completed'));
+
+-- Leagues whose draft time already passed were reported as active before
+UPDATE leagues SET status = 'active' WHERE draft_time <= CURRENT_TIMESTAMP AND status = 'pending';
\ No newline at end of file
@@ -0,0 +1,13 @@
+-- Fantasy teams: one per user per league, holding a roster of pro player ids.
+
+CREATE TABLE IF NOT EXISTS teams (
+    id BIGSERIAL PRIMARY KEY,
+    league_id BIGINT NOT NULL REFERENCES leagues(id) ON DELETE CASCADE,
+    owner_id BIGINT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    roster TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE (league_id, owner_id)
+);
+
+CREATE INDEX IF NOT EXISTS teams_owner_id_idx ON teams (owner_id);
\ No newline at end of file
@@ -0,0 +1,9 @@
+-- Store scoring types in their canonical lowercase form so exact-match filters find every league.
+
+UPDATE leagues SET scoring_type = LOWER(TRIM(scoring_type)) WHERE scoring_type <> LOWER(TRIM(scoring_type));
+
+-- Anything unrecognized was already reported as custom scoring
+UPDATE leagues SET scoring_type = 'custom' WHERE scoring_type NOT IN ('standard', 'ppr', 'custom');
+
+ALTER TABLE leagues ADD CONSTRAINT leagues_scoring_type_check
+    CHECK (scoring_type IN ('standard', 'ppr', 'custom'));
\ No newline at end of file
@@ -8,8 +8,25 @@ use argon2::{self, password_hash::{rand_core::OsRng, PasswordHash, PasswordHashe
 struct Claims {
     sub: String,
     exp: usize,
+    /// Unique token id used for revocation. Tokens issued before revocation
+    /// existed have none and can't be revoked.
+    #[serde(default)]
+    jti: String,
 }
 
+/// The parts of a validated token the guards need
+#[derive(Debug)]
+pub struct ValidatedToken {
+    pub user_id: i64,
+    /// Expiry in seconds since the epoch
+    pub expires_at: usize,
+    pub jti: String,
+}
+
+/// Version of the password hashing settings. Bump this when the Argon2 parameters
+/// (or a pepper) change so existing hashes are upgraded on the user's next login.
+pub const CURRENT_HASH_VERSION: i32 = 1;
+
 /// Hashes a password using Argon2
 pub fn hash_password(password: &str) -> String {
     let salt = SaltString::generate(&mut OsRng);
@@ -23,6 +40,54 @@ pub fn verify_password(password: &str, hash: &str) -> bool {
     Argon2::default().verify_password(password.as_bytes(), &parsed_hash).is_ok()
 }
 
+/// Checks whether a stored hash was produced with outdated settings and should be
+/// recomputed with the current ones
+pub fn needs_rehash(hash: &str, hash_version: i32) -> bool {
+    if hash_version < CURRENT_HASH_VERSION {
+        return true;
+    }
+
+    let parsed_hash = match PasswordHash::new(hash) {
+        Ok(parsed) => parsed,
+        Err(_) => return true,
+    };
+    let current = Argon2::default();
+    if parsed_hash.algorithm != argon2::Algorithm::default().ident()
+        || parsed_hash.version != Some(u32::from(argon2::Version::default()))
+    {
+        return true;
+    }
+
+    match argon2::Params::try_from(&parsed_hash) {
+        Ok(params) => {
+            params.m_cost() != current.params().m_cost()
+                || params.t_cost() != current.params().t_cost()
+                || params.p_cost() != current.params().p_cost()
+        }
+        Err(_) => true,
+    }
+}
+
+/// Token lifetime used when JWT_EXPIRY_SECONDS is unset or invalid (1 hour)
+const DEFAULT_TOKEN_EXPIRY_SECONDS: u64 = 3600;
+
+/// Token lifetime in seconds, read from JWT_EXPIRY_SECONDS
+fn token_expiry_seconds() -> u64 {
+    match std::env::var("JWT_EXPIRY_SECONDS") {
+        Ok(value) => match value.trim().parse::<u64>() {
+            Ok(seconds) if seconds > 0 => seconds,
+            _ => {
+                eprintln!(
+                    "Invalid JWT_EXPIRY_SECONDS {:?}, using default of {} seconds",
+                    value, DEFAULT_TOKEN_EXPIRY_SECONDS
+                );
+                DEFAULT_TOKEN_EXPIRY_SECONDS
+            }
+        },
+        Err(_) => DEFAULT_TOKEN_EXPIRY_SECONDS,
+    }
+}
+
 /// Generates a JWT token for a user
 pub fn generate_token(user_id: i64) -> Result<String, String> {
     let secret = match std::env::var("JWT_SECRET") {
@@ -36,27 +101,161 @@ pub fn generate_token(user_id: i64) -> Result<String, String> {
     let expiration = SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap()
-        .as_secs() + 3600; // 1 hour expiration
+        .as_secs() + token_expiry_seconds();
 
     let claims = Claims {
         sub: user_id.to_string(),
         exp: expiration as usize,
+        jti: uuid::Uuid::new_v4().to_string(),
     };
 
     encode(&Header::default(), &claims, &EncodingKey::from_secret(secret.as_bytes()))
         .map_err(|e| format!("Token generation failed: {:?}", e))
 }
 
-/// Validates a JWT token
-pub fn validate_token(token: &str) -> Result<i64, jsonwebtoken::errors::Error> {
-    println!("auth::validate_token: Validating token");
+/// How long after expiry a token may still be exchanged for a new one, in seconds
+pub const REFRESH_GRACE_SECONDS: u64 = 15 * 60;
+
+/// Issues a new token for a user whose current token was accepted by
+/// `validate_token_for_refresh`
+pub fn refresh_token(user_id: i64) -> Result<String, String> {
+    generate_token(user_id)
+}
+
+/// Validates a JWT token, returning the user id, expiry and token id. This checks
+/// the signature and expiry only; revocation is checked by the guards.
+pub fn validate_token_details(token: &str) -> Result<ValidatedToken, jsonwebtoken::errors::Error> {
+    let claims = decode_claims(token, &Validation::default())?;
+    into_validated(claims)
+}
+
+/// Validates a token presented for refresh. Unlike `validate_token_details`, a token that
+/// expired less than `REFRESH_GRACE_SECONDS` ago is still accepted.
+pub fn validate_token_for_refresh(token: &str) -> Result<ValidatedToken, jsonwebtoken::errors::Error> {
+    let mut validation = Validation::default();
+    validation.leeway = REFRESH_GRACE_SECONDS;
+    let claims = decode_claims(token, &validation)?;
+    into_validated(claims)
+}
+
+/// Decodes and verifies a token's signature and claims
+fn decode_claims(token: &str, validation: &Validation) -> Result<Claims, jsonwebtoken::errors::Error> {
     let secret = std::env::var("JWT_SECRET").expect("JWT_SECRET must be set");
     let token_data = decode::<Claims>(
         token,
         &DecodingKey::from_secret(secret.as_bytes()),
-        &Validation::default(),
+        validation,
     )?;
+    Ok(token_data.claims)
+}
+
+/// Reads the user id from the subject claim. A validly signed token can still
+/// carry a non-numeric subject; reject it instead of panicking the worker.
+fn into_validated(claims: Claims) -> Result<ValidatedToken, jsonwebtoken::errors::Error> {
+    let user_id = claims
+        .sub
+        .parse()
+        .map_err(|_| jsonwebtoken::errors::Error::from(jsonwebtoken::errors::ErrorKind::InvalidSubject))?;
+    Ok(ValidatedToken {
+        user_id,
+        expires_at: claims.exp,
+        jti: claims.jti,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonwebtoken::errors::ErrorKind;
+
+    fn claims(sub: &str) -> Claims {
+        Claims { sub: sub.to_string(), exp: 1_700_000_000, jti: "token-id".to_string() }
+    }
+
+    #[test]
+    fn numeric_subject_becomes_the_user_id() {
+        let validated = into_validated(claims("42")).unwrap();
+        assert_eq!(validated.user_id, 42);
+        assert_eq!(validated.expires_at, 1_700_000_000);
+        assert_eq!(validated.jti, "token-id");
+    }
+
+    #[test]
+    fn non_numeric_subject_is_rejected() {
+        for sub in ["abc", "", "42abc", "99999999999999999999"] {
+            let error = into_validated(claims(sub)).unwrap_err();
+            assert!(matches!(error.kind(), ErrorKind::InvalidSubject), "{:?} gave {:?}", sub, error);
+        }
+    }
+
+
+    fn now() -> u64 {
+        SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()
+    }
+
+    /// Generates a token and returns how far in the future its exp claim lies
+    fn generated_lifetime() -> u64 {
+        let issued_at = now();
+        let token = generate_token(1).unwrap();
+        validate_token_details(&token).unwrap().expires_at as u64 - issued_at
+    }
+
+    // Kept as one test so the runs don't race on the shared environment variable
+    #[test]
+    fn token_lifetime_follows_jwt_expiry_seconds() {
+        crate::test_support::init_env();
+
+        std::env::set_var("JWT_EXPIRY_SECONDS", "120");
+        assert!((120..=121).contains(&generated_lifetime()));
+
+        for invalid in ["soon", "0", "-5"] {
+            std::env::set_var("JWT_EXPIRY_SECONDS", invalid);
+            assert!((DEFAULT_TOKEN_EXPIRY_SECONDS..=DEFAULT_TOKEN_EXPIRY_SECONDS + 1).contains(&generated_lifetime()));
+        }
+
+        std::env::remove_var("JWT_EXPIRY_SECONDS");
+        assert!((DEFAULT_TOKEN_EXPIRY_SECONDS..=DEFAULT_TOKEN_EXPIRY_SECONDS + 1).contains(&generated_lifetime()));
+    }
+
+
+    #[test]
+    fn current_hash_does_not_need_rehash() {
+        let hash = hash_password("Fantasy-League-42");
+        assert!(!needs_rehash(&hash, CURRENT_HASH_VERSION));
+    }
+
+    #[test]
+    fn older_hash_version_needs_rehash() {
+        let hash = hash_password("Fantasy-League-42");
+        assert!(needs_rehash(&hash, CURRENT_HASH_VERSION - 1));
+    }
+
+    #[test]
+    fn hash_with_other_params_needs_rehash() {
+        let params = argon2::Params::new(8 * 1024, 1, 1, None).unwrap();
+        let weaker = Argon2::new(argon2::Algorithm::default(), argon2::Version::default(), params);
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = weaker.hash_password(b"Fantasy-League-42", &salt).unwrap().to_string();
+        assert!(needs_rehash(&hash, CURRENT_HASH_VERSION));
+    }
+
+
+    /// Signs a token for user 7 that expired `seconds_ago` seconds ago
+    fn expired_token(seconds_ago: u64) -> String {
+        crate::test_support::init_env();
+        let secret = std::env::var("JWT_SECRET").unwrap();
+        let claims = Claims { exp: (now() - seconds_ago) as usize, ..claims("7") };
+        encode(&Header::default(), &claims, &EncodingKey::from_secret(secret.as_bytes())).unwrap()
+    }
+
+    #[test]
+    fn recently_expired_token_can_be_refreshed() {
+        assert_eq!(validate_token_for_refresh(&expired_token(60)).unwrap().user_id, 7);
+    }
 
-    println!("auth::validate_token: Token validated successfully");
-    Ok(token_data.claims.sub.parse().unwrap())
+    #[test]
+    fn token_past_the_grace_period_cannot_be_refreshed() {
+        let error = validate_token_for_refresh(&expired_token(REFRESH_GRACE_SECONDS + 1)).unwrap_err();
+        assert!(matches!(error.kind(), ErrorKind::ExpiredSignature));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,172 @@
+use chrono::{DateTime, Duration, Utc};
+use crate::models::league::League;
+use crate::season;
+
+/// How long the draft event lasts in calendar apps
+const DRAFT_DURATION_HOURS: i64 = 2;
+
+/// Longest a content line may be, in octets, excluding the line break (RFC 5545 section 3.1)
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Builds an iCalendar (RFC 5545) feed for a league containing its draft and
+/// each regular-season week.
+pub fn league_calendar(league: &League) -> String {
+    let now = Utc::now();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//eFantasy//League Calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(&league.name)),
+    ];
+
+    push_event(
+        &mut lines,
+        &format!("league-{}-draft@efantasy", league.id),
+        now,
+        league.draft_time,
+        league.draft_time + Duration::hours(DRAFT_DURATION_HOURS),
+        &format!("{} draft", league.name),
+    );
+
+    let weeks = season::season_weeks();
+    for week in 1..=weeks {
+        push_event(
+            &mut lines,
+            &format!("league-{}-week-{}@efantasy", league.id, week),
+            now,
+            season::week_start(league.draft_time, week),
+            season::week_start(league.draft_time, week + 1),
+            &format!("{} - Week {}", league.name, week),
+        );
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+    // iCalendar requires CRLF line endings
+    lines.iter().map(|line| fold_line(line)).collect::<Vec<_>>().join("\r\n") + "\r\n"
+}
+
+/// Folds a content line so no physical line exceeds `MAX_LINE_OCTETS`.
+///
+/// Continuation lines start with a single space, which counts towards their length.
+/// Lines are only split between characters, never inside a multi-byte one.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len());
+    let mut line_octets = 0;
+    for ch in line.chars() {
+        if line_octets + ch.len_utf8() > MAX_LINE_OCTETS {
+            folded.push_str("\r\n ");
+            line_octets = 1;
+        }
+        folded.push(ch);
+        line_octets += ch.len_utf8();
+    }
+    folded
+}
+
+/// Appends a single VEVENT to the calendar
+fn push_event(
+    lines: &mut Vec<String>,
+    uid: &str,
+    stamp: DateTime<Utc>,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    summary: &str,
+) {
+    lines.push("BEGIN:VEVENT".to_string());
+    lines.push(format!("UID:{}", uid));
+    lines.push(format!("DTSTAMP:{}", format_datetime(stamp)));
+    lines.push(format!("DTSTART:{}", format_datetime(start)));
+    lines.push(format!("DTEND:{}", format_datetime(end)));
+    lines.push(format!("SUMMARY:{}", escape_text(summary)));
+    lines.push("END:VEVENT".to_string());
+}
+
+/// Formats a UTC timestamp in iCalendar's basic format, e.g. 20240101T180000Z
+fn format_datetime(datetime: DateTime<Utc>) -> String {
+    datetime.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escapes characters that have special meaning in iCalendar text values
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn league(name: &str) -> League {
+        let created = Utc.with_ymd_and_hms(2024, 8, 1, 12, 0, 0).unwrap();
+        League {
+            id: 7,
+            name: name.to_string(),
+            admin_id: 1,
+            max_teams: 10,
+            is_public: true,
+            draft_time: Utc.with_ymd_and_hms(2024, 9, 1, 18, 0, 0).unwrap(),
+            scoring_type: "standard".to_string(),
+            created_at: created,
+            updated_at: created,
+            participants: vec![1],
+            join_password: None,
+            locked: false,
+            draft_order: None,
+            status: "pending".to_string(),
+        }
+    }
+
+    /// Undoes line folding, giving back the logical content lines
+    fn unfold(calendar: &str) -> Vec<String> {
+        calendar
+            .replace("\r\n ", "")
+            .split("\r\n")
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn contains_the_draft_and_every_week() {
+        let calendar = league_calendar(&league("Summer Split"));
+        assert!(calendar.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(calendar.ends_with("END:VCALENDAR\r\n"));
+
+        let lines = unfold(&calendar);
+        let events = lines.iter().filter(|line| *line == "BEGIN:VEVENT").count();
+        assert_eq!(events, 1 + season::season_weeks() as usize);
+        assert_eq!(events, lines.iter().filter(|line| *line == "END:VEVENT").count());
+
+        let draft = lines.iter().position(|line| line == "UID:league-7-draft@efantasy").unwrap();
+        assert_eq!(lines[draft + 2], "DTSTART:20240901T180000Z");
+        assert_eq!(lines[draft + 3], "DTEND:20240901T200000Z");
+        assert_eq!(lines[draft + 4], "SUMMARY:Summer Split draft");
+    }
+
+    #[test]
+    fn escapes_special_characters_in_text() {
+        let lines = unfold(&league_calendar(&league("Mid; Top, Bot\\Sup")));
+        assert!(lines.contains(&r"X-WR-CALNAME:Mid\; Top\, Bot\\Sup".to_string()));
+    }
+
+    #[test]
+    fn folds_long_lines_at_75_octets() {
+        let name = "Très longue ligue é".repeat(10);
+        let calendar = league_calendar(&league(&name));
+        for line in calendar.split("\r\n") {
+            assert!(line.len() <= MAX_LINE_OCTETS, "{:?} is {} octets", line, line.len());
+        }
+        assert!(unfold(&calendar).contains(&format!("X-WR-CALNAME:{}", name)));
+    }
+
+    #[test]
+    fn fold_line_leaves_short_lines_alone() {
+        assert_eq!(fold_line("VERSION:2.0"), "VERSION:2.0");
+        let folded = fold_line(&"a".repeat(80));
+        assert_eq!(folded, format!("{}\r\n {}", "a".repeat(75), "a".repeat(5)));
+    }
+}
\ No newline at end of file
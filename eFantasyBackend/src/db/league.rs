@@ -1,7 +1,24 @@
+use std::collections::HashSet;
 use sqlx::PgPool;
-use crate::models::league::{League, NewLeague};
+use chrono::Utc;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use crate::models::league::{League, LeagueStatus, NewLeague, UpdateLeaguePartial, ScoringType};
 use crate::errors::LeagueError;
 
+/// Smallest allowed league size
+const MIN_MAX_TEAMS: i32 = 2;
+/// Largest allowed league size
+const MAX_MAX_TEAMS: i32 = 100;
+
+/// Checks that a league size is within the allowed bounds
+fn validate_max_teams(max_teams: i32) -> Result<(), LeagueError> {
+    if (MIN_MAX_TEAMS..=MAX_MAX_TEAMS).contains(&max_teams) {
+        Ok(())
+    } else {
+        Err(LeagueError::InvalidMaxTeams)
+    }
+}
+
 /// Creates a new league in the database
 ///
 /// # Arguments
@@ -14,11 +31,17 @@ use crate::errors::LeagueError;
 ///
 /// Returns the created League on success, or a LeagueError on failure
 pub async fn create_league(pool: &PgPool, new_league: NewLeague, admin_id: i64) -> Result<League, LeagueError> {
+    validate_max_teams(new_league.max_teams)?;
+    let scoring_type = ScoringType::parse(&new_league.scoring_type)?;
+
+    // Only the hash of the join password is ever stored
+    let join_password = new_league.join_password.as_deref().map(crate::auth::hash_password);
+
     sqlx::query_as!(
         League,
         r#"
-        INSERT INTO leagues (name, admin_id, max_teams, is_public, draft_time, scoring_type, created_at, updated_at)
-        VALUES ($1, $2, $3, $4, $5, $6, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+        INSERT INTO leagues (name, admin_id, max_teams, is_public, draft_time, scoring_type, participants, join_password, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, ARRAY[$2::BIGINT], $7, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
         RETURNING *
         "#,
         new_league.name,
@@ -26,9 +49,903 @@ pub async fn create_league(pool: &PgPool, new_league: NewLeague, admin_id: i64)
         new_league.max_teams,
         new_league.is_public,
         new_league.draft_time,
-        new_league.scoring_type
+        scoring_type.as_str(),
+        join_password
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)
+}
+
+/// Retrieves a league by its ID
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+///
+/// # Returns
+///
+/// Returns the League on success, LeagueError::NotFound if it doesn't exist
+pub async fn get_league_by_id(pool: &PgPool, league_id: i64) -> Result<League, LeagueError> {
+    sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE id = $1",
+        league_id
     )
     .fetch_one(pool)
     .await
-    .map_err(|e| LeagueError::DatabaseError(e))
+    .map_err(|e| match e {
+        sqlx::Error::RowNotFound => LeagueError::NotFound,
+        _ => LeagueError::DatabaseError(e),
+    })
+}
+
+/// Adds a user to a league's participants
+///
+/// Public leagues can be joined by anyone. Private leagues can only be joined
+/// by supplying the league's join password.
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league to join
+/// * `user_id` - The ID of the user joining
+/// * `password` - The join password, required for private leagues
+///
+/// # Returns
+///
+/// Returns the updated League on success, or a LeagueError on failure
+pub async fn join_league(pool: &PgPool, league_id: i64, user_id: i64, password: Option<&str>) -> Result<League, LeagueError> {
+    let mut tx = pool.begin().await?;
+
+    // Lock the league row so concurrent joins can't overfill it
+    let league = sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE id = $1 FOR UPDATE",
+        league_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or(LeagueError::NotFound)?;
+
+    if league.participants.contains(&user_id) {
+        return Err(LeagueError::AlreadyJoined);
+    }
+
+    if !league.is_public {
+        // Without a join password a private league can't be joined; hide it entirely
+        let hash = league.join_password.as_deref().ok_or(LeagueError::NotFound)?;
+        match password {
+            Some(password) if crate::auth::verify_password(password, hash) => {}
+            _ => return Err(LeagueError::InvalidJoinPassword),
+        }
+    }
+
+    league.assert_can_modify(user_id)?;
+
+    if league.draft_started() {
+        return Err(LeagueError::DraftAlreadyStarted);
+    }
+
+    if league.participants.len() >= league.max_teams as usize {
+        return Err(LeagueError::LeagueFull);
+    }
+
+    let league = sqlx::query_as!(
+        League,
+        r#"
+        UPDATE leagues
+        SET participants = array_append(participants, $1), draft_order = NULL, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $2
+        RETURNING *
+        "#,
+        user_id,
+        league_id
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(league)
+}
+
+/// Retrieves every league the user participates in
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `user_id` - The ID of the user
+///
+/// # Returns
+///
+/// Returns the user's leagues ordered by draft time, or a LeagueError on failure
+pub async fn get_user_leagues(pool: &PgPool, user_id: i64) -> Result<Vec<League>, LeagueError> {
+    sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE $1 = ANY(participants) ORDER BY draft_time ASC",
+        user_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)
+}
+
+/// Retrieves the most recently created public leagues
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `limit` - The maximum number of leagues to return
+/// * `offset` - The number of leagues to skip
+///
+/// # Returns
+///
+/// Returns the public leagues newest first, or a LeagueError on failure
+pub async fn get_recent_public_leagues(pool: &PgPool, limit: i64, offset: i64) -> Result<Vec<League>, LeagueError> {
+    sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE is_public = TRUE ORDER BY created_at DESC LIMIT $1 OFFSET $2",
+        limit,
+        offset
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)
+}
+
+/// Counts all public leagues
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+///
+/// # Returns
+///
+/// Returns the number of public leagues, or a LeagueError on failure
+pub async fn count_public_leagues(pool: &PgPool) -> Result<i64, LeagueError> {
+    let total = sqlx::query!(
+        r#"SELECT COUNT(*) as "total!" FROM leagues WHERE is_public = TRUE"#
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)?
+    .total;
+    Ok(total)
+}
+
+/// Locks or unlocks a league
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `admin_id` - The ID of the user making the change, who must be the league admin
+/// * `locked` - Whether the league should be locked
+///
+/// # Returns
+///
+/// Returns the updated League on success, or a LeagueError on failure
+pub async fn set_league_locked(pool: &PgPool, league_id: i64, admin_id: i64, locked: bool) -> Result<League, LeagueError> {
+    let league = get_league_by_id(pool, league_id).await?;
+    league.assert_visible_to(admin_id)?;
+    if league.admin_id != admin_id {
+        return Err(LeagueError::NotAuthorized);
+    }
+
+    sqlx::query_as!(
+        League,
+        r#"
+        UPDATE leagues
+        SET locked = $1, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $2
+        RETURNING *
+        "#,
+        locked,
+        league_id
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)
+}
+
+/// Applies a partial update to a league's settings
+///
+/// Only the provided fields change. Settings that shape the draft (draft time,
+/// size, scoring and participants) are frozen once the draft has started.
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `admin_id` - The ID of the user making the change, who must be the league admin
+/// * `update` - The fields to change
+///
+/// # Returns
+///
+/// Returns the updated League on success, or a LeagueError on failure
+pub async fn update_league_partial(pool: &PgPool, league_id: i64, admin_id: i64, update: UpdateLeaguePartial) -> Result<League, LeagueError> {
+    let mut tx = pool.begin().await?;
+
+    let league = sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE id = $1 FOR UPDATE",
+        league_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or(LeagueError::NotFound)?;
+
+    league.assert_visible_to(admin_id)?;
+    if league.admin_id != admin_id {
+        return Err(LeagueError::NotAuthorized);
+    }
+
+    let now = Utc::now();
+    let changes_draft_settings = update.draft_time.is_some()
+        || update.max_teams.is_some()
+        || update.scoring_type.is_some()
+        || update.participants.is_some();
+    if changes_draft_settings && league.draft_started() {
+        return Err(LeagueError::DraftAlreadyStarted);
+    }
+    if matches!(update.draft_time, Some(draft_time) if draft_time <= now) {
+        return Err(LeagueError::ValidationError("draft_time must be in the future".to_string()));
+    }
+    if let Some(participants) = &update.participants {
+        if !participants.contains(&league.admin_id) {
+            return Err(LeagueError::ValidationError("participants must include the league admin".to_string()));
+        }
+        let unique: HashSet<i64> = participants.iter().copied().collect();
+        if unique.len() != participants.len() {
+            return Err(LeagueError::ValidationError("participants must not contain duplicates".to_string()));
+        }
+        let existing = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM users WHERE id = ANY($1)"#,
+            participants
+        )
+        .fetch_one(&mut tx)
+        .await?;
+        if existing as usize != participants.len() {
+            return Err(LeagueError::ValidationError("participants must all be existing users".to_string()));
+        }
+    }
+    if let Some(max_teams) = update.max_teams {
+        validate_max_teams(max_teams)?;
+    }
+    let max_teams = update.max_teams.unwrap_or(league.max_teams);
+    let participant_count = update.participants.as_ref().unwrap_or(&league.participants).len();
+    if participant_count > max_teams.max(0) as usize {
+        return Err(LeagueError::InvalidMaxTeams);
+    }
+
+    let scoring_type = update
+        .scoring_type
+        .as_deref()
+        .map(|scoring_type| ScoringType::parse(scoring_type).map(|parsed| parsed.as_str()))
+        .transpose()?;
+    let join_password = update.join_password.as_deref().map(crate::auth::hash_password);
+
+    let league = sqlx::query_as!(
+        League,
+        r#"
+        UPDATE leagues
+        SET
+            name = COALESCE($1, name),
+            max_teams = COALESCE($2, max_teams),
+            is_public = COALESCE($3, is_public),
+            draft_time = COALESCE($4, draft_time),
+            scoring_type = COALESCE($5, scoring_type),
+            join_password = COALESCE($6, join_password),
+            participants = COALESCE($7, participants),
+            draft_order = CASE WHEN $7::BIGINT[] IS NULL THEN draft_order ELSE NULL END,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = $8
+        RETURNING *
+        "#,
+        update.name,
+        update.max_teams,
+        update.is_public,
+        update.draft_time,
+        scoring_type,
+        join_password,
+        update.participants.as_deref(),
+        league_id
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    // Participants dropped from the list lose their team along with their spot
+    if let Some(participants) = &update.participants {
+        sqlx::query!(
+            "DELETE FROM teams WHERE league_id = $1 AND NOT (owner_id = ANY($2))",
+            league_id,
+            participants
+        )
+        .execute(&mut tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(league)
+}
+
+/// Removes a participant from a league
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `admin_id` - The ID of the user making the change, who must be the league admin
+/// * `target_id` - The ID of the participant to remove
+///
+/// # Returns
+///
+/// Returns the updated League on success, or a LeagueError on failure
+pub async fn kick_participant(pool: &PgPool, league_id: i64, admin_id: i64, target_id: i64) -> Result<League, LeagueError> {
+    let mut tx = pool.begin().await?;
+
+    let league = sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE id = $1 FOR UPDATE",
+        league_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or(LeagueError::NotFound)?;
+
+    league.assert_visible_to(admin_id)?;
+    if league.admin_id != admin_id {
+        return Err(LeagueError::NotAuthorized);
+    }
+    if target_id == admin_id {
+        return Err(LeagueError::ValidationError("The league admin can't be kicked".to_string()));
+    }
+    if league.draft_started() {
+        return Err(LeagueError::DraftAlreadyStarted);
+    }
+    if !league.participants.contains(&target_id) {
+        return Err(LeagueError::NotInLeague);
+    }
+
+    let league = sqlx::query_as!(
+        League,
+        r#"
+        UPDATE leagues
+        SET participants = array_remove(participants, $1), draft_order = array_remove(draft_order, $1), updated_at = CURRENT_TIMESTAMP
+        WHERE id = $2
+        RETURNING *
+        "#,
+        target_id,
+        league_id
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM teams WHERE league_id = $1 AND owner_id = $2",
+        league_id,
+        target_id
+    )
+    .execute(&mut tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(league)
+}
+
+/// Hands the league admin role to another participant
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `current_admin_id` - The ID of the user making the change, who must be the league admin
+/// * `new_admin_id` - The ID of the participant who becomes admin
+///
+/// # Returns
+///
+/// Returns the updated League on success, or a LeagueError on failure
+pub async fn transfer_admin(pool: &PgPool, league_id: i64, current_admin_id: i64, new_admin_id: i64) -> Result<League, LeagueError> {
+    let mut tx = pool.begin().await?;
+
+    let league = sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE id = $1 FOR UPDATE",
+        league_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or(LeagueError::NotFound)?;
+
+    league.assert_visible_to(current_admin_id)?;
+    if league.admin_id != current_admin_id {
+        return Err(LeagueError::NotAuthorized);
+    }
+    if new_admin_id == current_admin_id {
+        return Err(LeagueError::ValidationError("User is already the league admin".to_string()));
+    }
+    if !league.participants.contains(&new_admin_id) {
+        return Err(LeagueError::NotInLeague);
+    }
+
+    let league = sqlx::query_as!(
+        League,
+        r#"
+        UPDATE leagues
+        SET admin_id = $1, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $2
+        RETURNING *
+        "#,
+        new_admin_id,
+        league_id
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(league)
+}
+
+/// Shuffles the participants into a random draft order and stores it
+///
+/// Any existing order is replaced. Joining, kicking or replacing participants
+/// clears or adjusts the stored order.
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `admin_id` - The ID of the user making the change, who must be the league admin
+/// * `seed` - Seeds the shuffle so the same seed always gives the same order; random if omitted
+///
+/// # Returns
+///
+/// Returns the updated League on success, or a LeagueError on failure
+pub async fn generate_draft_order(pool: &PgPool, league_id: i64, admin_id: i64, seed: Option<u64>) -> Result<League, LeagueError> {
+    let mut tx = pool.begin().await?;
+
+    let league = sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE id = $1 FOR UPDATE",
+        league_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or(LeagueError::NotFound)?;
+
+    league.assert_visible_to(admin_id)?;
+    if league.admin_id != admin_id {
+        return Err(LeagueError::NotAuthorized);
+    }
+    // Only the status matters here: the order can still be drawn once the scheduled
+    // draft time has passed, as long as the admin hasn't started the draft
+    if league.status() != LeagueStatus::Pending {
+        return Err(LeagueError::DraftAlreadyStarted);
+    }
+
+    let draft_order = shuffled(&league.participants, seed);
+
+    let league = sqlx::query_as!(
+        League,
+        r#"
+        UPDATE leagues
+        SET draft_order = $1, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $2
+        RETURNING *
+        "#,
+        &draft_order[..],
+        league_id
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(league)
+}
+
+/// Returns the participants in random order; the same seed always gives the same order
+fn shuffled(participants: &[i64], seed: Option<u64>) -> Vec<i64> {
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_entropy(),
+    };
+    let mut order = participants.to_vec();
+    order.shuffle(&mut rng);
+    order
+}
+
+/// Moves a league from `pending` to `drafting`
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `admin_id` - The ID of the user making the change, who must be the league admin
+///
+/// # Returns
+///
+/// Returns the updated League on success, or a LeagueError on failure
+pub async fn start_draft(pool: &PgPool, league_id: i64, admin_id: i64) -> Result<League, LeagueError> {
+    let mut tx = pool.begin().await?;
+
+    let league = sqlx::query_as!(
+        League,
+        "SELECT * FROM leagues WHERE id = $1 FOR UPDATE",
+        league_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or(LeagueError::NotFound)?;
+
+    league.assert_visible_to(admin_id)?;
+    if league.admin_id != admin_id {
+        return Err(LeagueError::NotAuthorized);
+    }
+    if league.status() != LeagueStatus::Pending {
+        return Err(LeagueError::InvalidStateTransition);
+    }
+    if league.draft_order.as_ref().is_none_or(|order| order.is_empty()) {
+        return Err(LeagueError::ValidationError("Generate a draft order before starting the draft".to_string()));
+    }
+
+    let league = sqlx::query_as!(
+        League,
+        r#"
+        UPDATE leagues
+        SET status = $1, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $2
+        RETURNING *
+        "#,
+        LeagueStatus::Drafting.as_str(),
+        league_id
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(league)
+}
+
+/// Searches public leagues by name, optionally filtered by scoring type
+///
+/// Leagues whose name starts with the query come before those that merely contain it.
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `name_query` - The text to look for in league names, matched case-insensitively
+/// * `scoring_type` - Only return leagues with this scoring type, if given
+/// * `limit` - The maximum number of leagues to return
+///
+/// # Returns
+///
+/// Returns the matching public leagues, or a LeagueError on failure
+pub async fn search_public_leagues(pool: &PgPool, name_query: &str, scoring_type: Option<&str>, limit: i64) -> Result<Vec<League>, LeagueError> {
+    let pattern = escape_like(name_query);
+    sqlx::query_as!(
+        League,
+        r#"
+        SELECT * FROM leagues
+        WHERE is_public = TRUE
+          AND name ILIKE '%' || $1 || '%' ESCAPE '\'
+          AND ($2::TEXT IS NULL OR scoring_type = $2)
+        ORDER BY (name ILIKE $1 || '%' ESCAPE '\') DESC, created_at DESC
+        LIMIT $3
+        "#,
+        pattern,
+        scoring_type,
+        limit
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)
+}
+
+/// Escapes LIKE wildcards so user input only ever matches literally
+fn escape_like(input: &str) -> String {
+    input
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// Retrieves public leagues the user could join right now
+///
+/// A league qualifies if it is public, unlocked, not full, hasn't drafted yet, and
+/// doesn't already include the user. Fuller leagues come first, then the soonest drafts.
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `user_id` - The ID of the user looking for a league
+/// * `limit` - The maximum number of leagues to return
+///
+/// # Returns
+///
+/// Returns the joinable leagues, or a LeagueError on failure
+pub async fn discover_leagues(pool: &PgPool, user_id: i64, limit: i64) -> Result<Vec<League>, LeagueError> {
+    sqlx::query_as!(
+        League,
+        r#"
+        SELECT * FROM leagues
+        WHERE is_public
+            AND NOT locked
+            AND cardinality(participants) < max_teams
+            AND draft_time > CURRENT_TIMESTAMP
+            AND status = 'pending'
+            AND NOT ($1 = ANY(participants))
+        ORDER BY cardinality(participants)::FLOAT8 / GREATEST(max_teams, 1) DESC, draft_time ASC
+        LIMIT $2
+        "#,
+        user_id,
+        limit
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::team::NewTeam;
+    use crate::test_support;
+
+    #[test]
+    fn escape_like_escapes_wildcards() {
+        assert_eq!(escape_like("100%"), "100\\%");
+        assert_eq!(escape_like("top_lane"), "top\\_lane");
+    }
+
+    #[test]
+    fn escape_like_escapes_the_escape_character_first() {
+        assert_eq!(escape_like("a\\%"), "a\\\\\\%");
+    }
+
+    #[test]
+    fn escape_like_leaves_plain_text_alone() {
+        assert_eq!(escape_like("Summer Split 2024"), "Summer Split 2024");
+    }
+
+
+    fn participants_update(participants: Vec<i64>) -> UpdateLeaguePartial {
+        UpdateLeaguePartial { participants: Some(participants), ..Default::default() }
+    }
+
+    #[sqlx::test]
+    async fn participants_update_rejects_duplicates(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+
+        let result = update_league_partial(&pool, league.id, admin.id, participants_update(vec![admin.id, admin.id])).await;
+        assert!(matches!(result, Err(LeagueError::ValidationError(_))));
+    }
+
+    #[sqlx::test]
+    async fn participants_update_rejects_unknown_users(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+
+        let result = update_league_partial(&pool, league.id, admin.id, participants_update(vec![admin.id, admin.id + 1000])).await;
+        assert!(matches!(result, Err(LeagueError::ValidationError(_))));
+    }
+
+    #[sqlx::test]
+    async fn name_only_update_leaves_other_settings_alone(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+
+        let update = UpdateLeaguePartial { name: Some("Winter Split".to_string()), ..Default::default() };
+        let updated = update_league_partial(&pool, league.id, admin.id, update).await.unwrap();
+
+        assert_eq!(updated.name, "Winter Split");
+        assert_eq!(updated.max_teams, league.max_teams);
+        assert_eq!(updated.is_public, league.is_public);
+        assert_eq!(updated.draft_time, league.draft_time);
+        assert_eq!(updated.scoring_type, league.scoring_type);
+        assert_eq!(updated.join_password, league.join_password);
+        assert_eq!(updated.participants, league.participants);
+        assert_eq!(updated.draft_order, league.draft_order);
+    }
+
+
+    #[test]
+    fn validate_max_teams_enforces_bounds() {
+        for rejected in [0, -1, 1, 101] {
+            assert!(matches!(validate_max_teams(rejected), Err(LeagueError::InvalidMaxTeams)), "{} should be rejected", rejected);
+        }
+        for accepted in [2, 100] {
+            assert!(validate_max_teams(accepted).is_ok(), "{} should be accepted", accepted);
+        }
+    }
+
+    #[sqlx::test]
+    async fn max_teams_cannot_drop_below_participant_count(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        for name in ["second", "third"] {
+            let user = test_support::user(&pool, name).await;
+            join_league(&pool, league.id, user.id, None).await.unwrap();
+        }
+
+        let update = UpdateLeaguePartial { max_teams: Some(2), ..Default::default() };
+        let result = update_league_partial(&pool, league.id, admin.id, update).await;
+        assert!(matches!(result, Err(LeagueError::InvalidMaxTeams)));
+
+        let update = UpdateLeaguePartial { max_teams: Some(3), ..Default::default() };
+        let updated = update_league_partial(&pool, league.id, admin.id, update).await.unwrap();
+        assert_eq!(updated.max_teams, 3);
+    }
+
+
+    #[test]
+    fn shuffled_is_a_reproducible_permutation() {
+        let participants: Vec<i64> = (1..=12).collect();
+        let order = shuffled(&participants, Some(42));
+
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, participants);
+        assert_eq!(shuffled(&participants, Some(42)), order);
+    }
+
+
+    async fn private_league_with_password(pool: &PgPool, admin_id: i64) -> League {
+        create_league(pool, NewLeague {
+            name: "Invite Only".to_string(),
+            max_teams: 4,
+            is_public: false,
+            draft_time: Utc::now() + chrono::Duration::days(7),
+            scoring_type: "standard".to_string(),
+            join_password: Some("open-sesame".to_string()),
+        }, admin_id)
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn correct_join_password_joins_a_private_league(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let user = test_support::user(&pool, "joiner").await;
+        let league = private_league_with_password(&pool, admin.id).await;
+
+        let joined = join_league(&pool, league.id, user.id, Some("open-sesame")).await.unwrap();
+        assert_eq!(joined.participants, vec![admin.id, user.id]);
+    }
+
+    #[sqlx::test]
+    async fn wrong_join_password_is_rejected(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let user = test_support::user(&pool, "joiner").await;
+        let league = private_league_with_password(&pool, admin.id).await;
+
+        for password in [Some("open-barley"), None] {
+            let result = join_league(&pool, league.id, user.id, password).await;
+            assert!(matches!(result, Err(LeagueError::InvalidJoinPassword)));
+        }
+        assert_eq!(get_league_by_id(&pool, league.id).await.unwrap().participants, vec![admin.id]);
+    }
+
+
+    #[sqlx::test]
+    async fn only_the_admin_can_kick(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let member = test_support::user(&pool, "member").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        join_league(&pool, league.id, member.id, None).await.unwrap();
+
+        let result = kick_participant(&pool, league.id, member.id, admin.id).await;
+        assert!(matches!(result, Err(LeagueError::NotAuthorized)));
+    }
+
+    #[sqlx::test]
+    async fn admin_cannot_kick_themselves(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+
+        let result = kick_participant(&pool, league.id, admin.id, admin.id).await;
+        assert!(matches!(result, Err(LeagueError::ValidationError(_))));
+    }
+
+    #[sqlx::test]
+    async fn kicked_participant_loses_their_spot_and_team(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let member = test_support::user(&pool, "member").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        join_league(&pool, league.id, member.id, None).await.unwrap();
+        crate::db::team::create_team(&pool, league.id, member.id, NewTeam { name: "Baron Stealers".to_string() }).await.unwrap();
+
+        let league = kick_participant(&pool, league.id, admin.id, member.id).await.unwrap();
+        assert_eq!(league.participants, vec![admin.id]);
+        assert!(crate::db::team::get_teams_by_league(&pool, league.id).await.unwrap().is_empty());
+    }
+
+
+    #[sqlx::test]
+    async fn only_the_admin_can_transfer_the_role(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let member = test_support::user(&pool, "member").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        join_league(&pool, league.id, member.id, None).await.unwrap();
+
+        let result = transfer_admin(&pool, league.id, member.id, member.id).await;
+        assert!(matches!(result, Err(LeagueError::NotAuthorized)));
+        assert_eq!(get_league_by_id(&pool, league.id).await.unwrap().admin_id, admin.id);
+    }
+
+    #[sqlx::test]
+    async fn admin_role_only_goes_to_participants(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let outsider = test_support::user(&pool, "outsider").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+
+        let result = transfer_admin(&pool, league.id, admin.id, outsider.id).await;
+        assert!(matches!(result, Err(LeagueError::NotInLeague)));
+
+        join_league(&pool, league.id, outsider.id, None).await.unwrap();
+        let league = transfer_admin(&pool, league.id, admin.id, outsider.id).await.unwrap();
+        assert_eq!(league.admin_id, outsider.id);
+    }
+
+
+    #[sqlx::test]
+    async fn draft_starts_once_an_order_is_generated(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let member = test_support::user(&pool, "member").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        join_league(&pool, league.id, member.id, None).await.unwrap();
+
+        let league = generate_draft_order(&pool, league.id, admin.id, Some(7)).await.unwrap();
+        assert_eq!(league.draft_order.as_deref().map(<[i64]>::len), Some(2));
+
+        let league = start_draft(&pool, league.id, admin.id).await.unwrap();
+        assert_eq!(league.status(), LeagueStatus::Drafting);
+    }
+
+    #[sqlx::test]
+    async fn draft_cannot_start_without_an_order(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+
+        let result = start_draft(&pool, league.id, admin.id).await;
+        assert!(matches!(result, Err(LeagueError::ValidationError(_))));
+        assert_eq!(get_league_by_id(&pool, league.id).await.unwrap().status(), LeagueStatus::Pending);
+    }
+
+    #[sqlx::test]
+    async fn draft_order_can_be_generated_after_the_draft_time_while_pending(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        sqlx::query!("UPDATE leagues SET draft_time = NOW() - INTERVAL '1 hour' WHERE id = $1", league.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let league = generate_draft_order(&pool, league.id, admin.id, None).await.unwrap();
+        assert_eq!(league.draft_order, Some(vec![admin.id]));
+
+        let league = start_draft(&pool, league.id, admin.id).await.unwrap();
+        let result = generate_draft_order(&pool, league.id, admin.id, None).await;
+        assert!(matches!(result, Err(LeagueError::DraftAlreadyStarted)));
+    }
+
+
+    #[sqlx::test]
+    async fn discover_skips_full_and_already_joined_leagues(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let filler = test_support::user(&pool, "filler").await;
+        let viewer = test_support::user(&pool, "viewer").await;
+
+        let open = test_support::league(&pool, admin.id, true).await;
+        let full = test_support::league(&pool, admin.id, true).await;
+        join_league(&pool, full.id, filler.id, None).await.unwrap();
+        let update = UpdateLeaguePartial { max_teams: Some(2), ..Default::default() };
+        update_league_partial(&pool, full.id, admin.id, update).await.unwrap();
+        let joined = test_support::league(&pool, admin.id, true).await;
+        join_league(&pool, joined.id, viewer.id, None).await.unwrap();
+
+        let ids: Vec<i64> = discover_leagues(&pool, viewer.id, 20).await.unwrap().iter().map(|league| league.id).collect();
+        assert_eq!(ids, vec![open.id]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,62 @@
+use sqlx::PgPool;
+use crate::models::message::LeagueMessage;
+use crate::errors::LeagueError;
+
+/// Posts a message to a league's message board
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `author_id` - The ID of the posting user
+/// * `body` - The message text
+///
+/// # Returns
+///
+/// Returns the created LeagueMessage on success, or a LeagueError on failure
+pub async fn post_message(pool: &PgPool, league_id: i64, author_id: i64, body: &str) -> Result<LeagueMessage, LeagueError> {
+    sqlx::query_as!(
+        LeagueMessage,
+        r#"
+        INSERT INTO league_messages (league_id, author_id, body, created_at)
+        VALUES ($1, $2, $3, CURRENT_TIMESTAMP)
+        RETURNING *
+        "#,
+        league_id,
+        author_id,
+        body
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)
+}
+
+/// Retrieves a page of a league's messages, newest first
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `before` - Only return messages older than this message ID, for paging backwards
+/// * `limit` - The maximum number of messages to return
+///
+/// # Returns
+///
+/// Returns the messages in reverse-chronological order, or a LeagueError on failure
+pub async fn get_messages(pool: &PgPool, league_id: i64, before: Option<i64>, limit: i64) -> Result<Vec<LeagueMessage>, LeagueError> {
+    sqlx::query_as!(
+        LeagueMessage,
+        r#"
+        SELECT * FROM league_messages
+        WHERE league_id = $1 AND ($2::BIGINT IS NULL OR id < $2)
+        ORDER BY id DESC
+        LIMIT $3
+        "#,
+        league_id,
+        before,
+        limit
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(LeagueError::DatabaseError)
+}
\ No newline at end of file
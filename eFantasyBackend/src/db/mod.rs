@@ -1,3 +1,6 @@
 pub mod user;
 pub mod pro;
-pub mod league;
\ No newline at end of file
+pub mod league;
+pub mod message;
+pub mod token;
+pub mod team;
\ No newline at end of file
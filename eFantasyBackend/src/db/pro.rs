@@ -1,6 +1,6 @@
 use mongodb::Collection;
-use mongodb::bson::{doc, oid::ObjectId};
-use crate::models::pro::ProPlayer;
+use mongodb::bson::{doc, Bson, Document};
+use crate::models::pro::{ProPlayer, PlayerId, stat_field};
 
 /// Retrieves a pro player from the database by their ID.
 ///
@@ -11,32 +11,235 @@ use crate::models::pro::ProPlayer;
 /// # Arguments
 ///
 /// * `db` - A reference to the MongoDB database
-/// * `pro_id` - A string slice containing the MongoDB ObjectId of the pro player
+/// * `pro_id` - The validated id of the pro player
 ///
 /// # Returns
 ///
 /// * `Ok(ProPlayer)` if the player is found
 /// * `Err(String)` if there's an error (e.g., player not found, database error)
-pub async fn get_pro_player_by_id(db: &mongodb::Database, pro_id: &str) -> Result<ProPlayer, String> {
+pub async fn get_pro_player_by_id(db: &mongodb::Database, pro_id: &PlayerId) -> Result<ProPlayer, String> {
     // Retrieve the collection name from environment variables
     let collection_name = std::env::var("MONGODB_PRO_PLAYER_COLLECTION")
         .map_err(|_| "MONGODB_PRO_PLAYER_COLLECTION environment variable not set".to_string())?;
-    
-    println!("Accessing collection: {}", collection_name);
 
     // Get a handle to the pro players collection
     let collection: Collection<ProPlayer> = db.collection(&collection_name);
 
-    // Parse the provided ID string into a MongoDB ObjectId
-    let object_id = ObjectId::parse_str(pro_id)
-        .map_err(|_| "Invalid ObjectId format".to_string())?;
-
     // Attempt to find the document with the given ID
-    let result = collection.find_one(doc! { "_id": object_id }).await
+    let result = collection.find_one(doc! { "_id": pro_id.object_id() }).await
         .map_err(|e| format!("Database error: {}", e))?;
-    
-    println!("Query result: {:?}", result);
 
     // If the document is found, return it; otherwise, return an error
     result.ok_or_else(|| "Pro player not found".to_string())
+}
+
+/// Retrieves pro players whose current name or any previous alias matches the given name.
+///
+/// # Arguments
+///
+/// * `db` - A reference to the MongoDB database
+/// * `name` - The in-game name to search for
+///
+/// # Returns
+///
+/// * `Ok(Vec<ProPlayer>)` with every matching player (possibly empty)
+/// * `Err(String)` if there's a database error
+pub async fn find_pro_players_by_name(db: &mongodb::Database, name: &str) -> Result<Vec<ProPlayer>, String> {
+    let collection_name = std::env::var("MONGODB_PRO_PLAYER_COLLECTION")
+        .map_err(|_| "MONGODB_PRO_PLAYER_COLLECTION environment variable not set".to_string())?;
+    let collection: Collection<ProPlayer> = db.collection(&collection_name);
+
+    let mut cursor = collection.find(name_filter(name)).await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut players = Vec::new();
+    while cursor.advance().await.map_err(|e| format!("Database error: {}", e))? {
+        players.push(cursor.deserialize_current().map_err(|e| format!("Database error: {}", e))?);
+    }
+    Ok(players)
+}
+
+/// Builds the filter matching players whose current name or any alias equals `name`
+fn name_filter(name: &str) -> Document {
+    // A plain equality on an array field matches any element, so this covers every alias
+    doc! { "$or": [ { "name": name }, { "aliases": name } ] }
+}
+
+/// Retrieves the pro players with the given IDs.
+///
+/// # Arguments
+///
+/// * `db` - A reference to the MongoDB database
+/// * `ids` - The validated ids of the pro players
+///
+/// # Returns
+///
+/// * `Ok(Vec<ProPlayer>)` with every player found; missing ids are skipped
+/// * `Err(String)` if there's a database error
+pub async fn get_pro_players_by_ids(db: &mongodb::Database, ids: &[PlayerId]) -> Result<Vec<ProPlayer>, String> {
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let collection_name = std::env::var("MONGODB_PRO_PLAYER_COLLECTION")
+        .map_err(|_| "MONGODB_PRO_PLAYER_COLLECTION environment variable not set".to_string())?;
+    let collection: Collection<ProPlayer> = db.collection(&collection_name);
+
+    let object_ids: Vec<_> = ids.iter().map(PlayerId::object_id).collect();
+    let mut cursor = collection.find(doc! { "_id": { "$in": object_ids } }).await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut players = Vec::new();
+    while cursor.advance().await.map_err(|e| format!("Database error: {}", e))? {
+        players.push(cursor.deserialize_current().map_err(|e| format!("Database error: {}", e))?);
+    }
+    Ok(players)
+}
+
+/// Field the aggregation pipelines store a player's parsed stat in while sorting
+const STAT_VALUE_FIELD: &str = "_stat_value";
+
+/// Builds an aggregation expression that parses a scraped stat string into a double,
+/// mirroring `parse_stat`: commas and surrounding spaces or `%` are dropped, and
+/// missing or unparseable values such as "-" become null.
+fn parsed_stat_expr(field: &str) -> Document {
+    doc! {
+        "$convert": {
+            "input": {
+                "$trim": {
+                    "input": { "$replaceAll": { "input": format!("${}", field), "find": ",", "replacement": "" } },
+                    "chars": " %",
+                }
+            },
+            "to": "double",
+            "onError": Bson::Null,
+            "onNull": Bson::Null,
+        }
+    }
+}
+
+/// Retrieves the top pro players for a position, ranked by a numeric stat.
+///
+/// Stats are stored as scraped strings in MongoDB, so the query parses them into
+/// numbers before sorting. Players without a usable value for the stat are left
+/// out of the ranking.
+///
+/// # Arguments
+///
+/// * `db` - A reference to the MongoDB database
+/// * `position` - The position to filter on (e.g., "MID")
+/// * `stat` - The stat to rank by, one of `RANKABLE_STATS`
+/// * `limit` - The maximum number of players to return
+///
+/// # Returns
+///
+/// * `Ok(Vec<ProPlayer>)` with the players sorted best first
+/// * `Err(String)` if the stat is unknown or there's a database error
+pub async fn get_top_players(db: &mongodb::Database, position: &str, stat: &str, limit: usize) -> Result<Vec<ProPlayer>, String> {
+    let collection_name = std::env::var("MONGODB_PRO_PLAYER_COLLECTION")
+        .map_err(|_| "MONGODB_PRO_PLAYER_COLLECTION environment variable not set".to_string())?;
+    let collection: Collection<ProPlayer> = db.collection(&collection_name);
+    let field = stat_field(stat).ok_or_else(|| format!("Unknown stat: {}", stat))?;
+
+    let pipeline = vec![
+        doc! { "$match": { "position": position } },
+        doc! { "$addFields": { STAT_VALUE_FIELD: parsed_stat_expr(field) } },
+        doc! { "$match": { STAT_VALUE_FIELD: { "$ne": Bson::Null } } },
+        doc! { "$sort": { STAT_VALUE_FIELD: -1, "_id": 1 } },
+        doc! { "$limit": limit as i64 },
+        doc! { "$project": { STAT_VALUE_FIELD: 0 } },
+    ];
+    let mut cursor = collection.aggregate(pipeline).with_type::<ProPlayer>().await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let mut players = Vec::new();
+    while cursor.advance().await.map_err(|e| format!("Database error: {}", e))? {
+        players.push(cursor.deserialize_current().map_err(|e| format!("Database error: {}", e))?);
+    }
+    Ok(players)
+}
+
+/// Lists pro players a page at a time, optionally sorted by name or a stat.
+///
+/// Sorting and paging always happen in the query. Stats are stored as scraped
+/// strings, so sorting by a stat parses them into numbers first; players without
+/// a usable value for the stat always come last.
+///
+/// # Arguments
+///
+/// * `db` - A reference to the MongoDB database
+/// * `limit` - The maximum number of players to return
+/// * `offset` - The number of players to skip
+/// * `sort_by` - "name" or one of `RANKABLE_STATS`; unsorted (insertion order) if `None`
+/// * `descending` - Whether to sort from highest to lowest
+///
+/// # Returns
+///
+/// * `Ok((Vec<ProPlayer>, u64))` with the page of players and the total number of players
+/// * `Err(String)` if there's a database error
+pub async fn list_pro_players(db: &mongodb::Database, limit: usize, offset: usize, sort_by: Option<&str>, descending: bool) -> Result<(Vec<ProPlayer>, u64), String> {
+    let collection_name = std::env::var("MONGODB_PRO_PLAYER_COLLECTION")
+        .map_err(|_| "MONGODB_PRO_PLAYER_COLLECTION environment variable not set".to_string())?;
+    let collection: Collection<ProPlayer> = db.collection(&collection_name);
+
+    let total = collection.count_documents(doc! {}).await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let direction = if descending { -1 } else { 1 };
+    let stat = match sort_by {
+        Some(stat) if stat != "name" => Some(stat),
+        _ => None,
+    };
+
+    let players = match stat {
+        None => {
+            let sort = match sort_by {
+                Some(_) => doc! { "name": direction, "_id": 1 },
+                None => doc! { "_id": 1 },
+            };
+            let mut cursor = collection.find(doc! {})
+                .sort(sort)
+                .skip(offset as u64)
+                .limit(limit as i64)
+                .await
+                .map_err(|e| format!("Database error: {}", e))?;
+
+            let mut players = Vec::new();
+            while cursor.advance().await.map_err(|e| format!("Database error: {}", e))? {
+                players.push(cursor.deserialize_current().map_err(|e| format!("Database error: {}", e))?);
+            }
+            players
+        }
+        Some(stat) => {
+            let field = stat_field(stat).ok_or_else(|| format!("Unknown stat: {}", stat))?;
+            let pipeline = vec![
+                doc! { "$addFields": { STAT_VALUE_FIELD: parsed_stat_expr(field) } },
+                doc! { "$addFields": { "_stat_missing": { "$eq": [format!("${}", STAT_VALUE_FIELD), Bson::Null] } } },
+                doc! { "$sort": { "_stat_missing": 1, STAT_VALUE_FIELD: direction, "_id": 1 } },
+                doc! { "$skip": offset as i64 },
+                doc! { "$limit": limit as i64 },
+                doc! { "$project": { STAT_VALUE_FIELD: 0, "_stat_missing": 0 } },
+            ];
+            let mut cursor = collection.aggregate(pipeline).with_type::<ProPlayer>().await
+                .map_err(|e| format!("Database error: {}", e))?;
+
+            let mut players = Vec::new();
+            while cursor.advance().await.map_err(|e| format!("Database error: {}", e))? {
+                players.push(cursor.deserialize_current().map_err(|e| format!("Database error: {}", e))?);
+            }
+            players
+        }
+    };
+
+    Ok((players, total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_filter_matches_the_name_or_any_alias() {
+        assert_eq!(name_filter("Faker"), doc! { "$or": [ { "name": "Faker" }, { "aliases": "Faker" } ] });
+    }
 }
\ No newline at end of file
@@ -0,0 +1,319 @@
+use sqlx::PgPool;
+use crate::models::team::{Team, NewTeam};
+use crate::models::pro::{PlayerId, ProPlayer};
+use crate::errors::TeamError;
+
+/// Roster size used when MAX_ROSTER_SIZE is unset or invalid
+const DEFAULT_MAX_ROSTER_SIZE: usize = 5;
+
+/// Maximum number of players on a roster, read from MAX_ROSTER_SIZE
+pub fn max_roster_size() -> usize {
+    std::env::var("MAX_ROSTER_SIZE")
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|size| *size > 0)
+        .unwrap_or(DEFAULT_MAX_ROSTER_SIZE)
+}
+
+/// Creates a team for a participant of a league
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+/// * `owner_id` - The ID of the user creating the team, who must be a participant
+/// * `new_team` - The data for the new team
+///
+/// # Returns
+///
+/// Returns the created Team on success, or TeamError::AlreadyHasTeam if the user
+/// already has a team in this league
+pub async fn create_team(pool: &PgPool, league_id: i64, owner_id: i64, new_team: NewTeam) -> Result<Team, TeamError> {
+    let league = crate::db::league::get_league_by_id(pool, league_id).await?;
+    league.assert_member(owner_id)?;
+    league.assert_can_modify(owner_id)?;
+
+    sqlx::query_as!(
+        Team,
+        r#"
+        INSERT INTO teams (league_id, owner_id, name, created_at)
+        VALUES ($1, $2, $3, CURRENT_TIMESTAMP)
+        RETURNING *
+        "#,
+        league_id,
+        owner_id,
+        new_team.name.trim()
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| match e {
+        // One team per user per league, enforced by a unique index
+        sqlx::Error::Database(ref db_err) if db_err.code().as_deref() == Some("23505") => TeamError::AlreadyHasTeam,
+        _ => TeamError::DatabaseError(e),
+    })
+}
+
+/// Retrieves a team by its ID
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `team_id` - The ID of the team
+///
+/// # Returns
+///
+/// Returns the Team on success, TeamError::NotFound if it doesn't exist
+pub async fn get_team(pool: &PgPool, team_id: i64) -> Result<Team, TeamError> {
+    sqlx::query_as!(
+        Team,
+        "SELECT * FROM teams WHERE id = $1",
+        team_id
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::RowNotFound => TeamError::NotFound,
+        _ => TeamError::DatabaseError(e),
+    })
+}
+
+/// Retrieves every team in a league
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `league_id` - The ID of the league
+///
+/// # Returns
+///
+/// Returns the league's teams in creation order, or a TeamError on failure
+pub async fn get_teams_by_league(pool: &PgPool, league_id: i64) -> Result<Vec<Team>, TeamError> {
+    sqlx::query_as!(
+        Team,
+        "SELECT * FROM teams WHERE league_id = $1 ORDER BY created_at ASC",
+        league_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(TeamError::DatabaseError)
+}
+
+/// Adds a pro player to a team's roster
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `mongo_db` - The MongoDB database holding the pro players
+/// * `team_id` - The ID of the team
+/// * `owner_id` - The ID of the user making the change, who must own the team
+/// * `player_id` - The ID of the pro player to add
+///
+/// # Returns
+///
+/// Returns the updated Team on success, or a TeamError on failure
+pub async fn add_player_to_roster(pool: &PgPool, mongo_db: &mongodb::Database, team_id: i64, owner_id: i64, player_id: &PlayerId) -> Result<Team, TeamError> {
+    let mut tx = pool.begin().await?;
+
+    // Lock the team row so concurrent adds can't overfill the roster
+    let team = sqlx::query_as!(
+        Team,
+        "SELECT * FROM teams WHERE id = $1 FOR UPDATE",
+        team_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or(TeamError::NotFound)?;
+
+    if team.owner_id != owner_id {
+        return Err(TeamError::NotAuthorized);
+    }
+    // Owners kicked from the league lose control of their team
+    let league = crate::db::league::get_league_by_id(pool, team.league_id).await?;
+    league.assert_member(owner_id)?;
+    league.assert_can_modify(owner_id)?;
+
+    check_roster_space(&team.roster, player_id, max_roster_size())?;
+
+    let player = match crate::db::pro::get_pro_player_by_id(mongo_db, player_id).await {
+        Ok(player) => Some(player),
+        Err(e) if e == "Pro player not found" => None,
+        Err(e) => return Err(TeamError::PlayerLookupFailed(e)),
+    };
+
+    // Rosters only store ids, so look up the current players to see which positions are taken
+    let roster_ids: Vec<PlayerId> = team.roster.iter().filter_map(|id| PlayerId::parse(id).ok()).collect();
+    let roster_players = crate::db::pro::get_pro_players_by_ids(mongo_db, &roster_ids)
+        .await
+        .map_err(TeamError::PlayerLookupFailed)?;
+    check_position_open(player.as_ref(), &roster_players)?;
+
+    let team = sqlx::query_as!(
+        Team,
+        r#"
+        UPDATE teams
+        SET roster = array_append(roster, $1)
+        WHERE id = $2
+        RETURNING *
+        "#,
+        player_id.as_str(),
+        team_id
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(team)
+}
+
+/// Checks that a roster doesn't already hold the player and has room for one more
+fn check_roster_space(roster: &[String], player_id: &PlayerId, max_size: usize) -> Result<(), TeamError> {
+    if roster.iter().any(|id| id == player_id.as_str()) {
+        return Err(TeamError::AlreadyOnRoster);
+    }
+    if roster.len() >= max_size {
+        return Err(TeamError::RosterFull);
+    }
+    Ok(())
+}
+
+/// Checks that the player exists, has a known position, and that no rostered
+/// player already fills that position
+fn check_position_open(player: Option<&ProPlayer>, roster_players: &[ProPlayer]) -> Result<(), TeamError> {
+    let player = player.ok_or(TeamError::PlayerNotFound)?;
+    let position = player.roster_position().ok_or(TeamError::UnknownPosition)?;
+    if roster_players.iter().any(|rostered| rostered.roster_position() == Some(position)) {
+        return Err(TeamError::PositionFilled);
+    }
+    Ok(())
+}
+
+/// Removes a pro player from a team's roster
+///
+/// # Arguments
+///
+/// * `pool` - The database connection pool
+/// * `team_id` - The ID of the team
+/// * `owner_id` - The ID of the user making the change, who must own the team
+/// * `player_id` - The ID of the pro player to drop
+///
+/// # Returns
+///
+/// Returns the updated Team on success, or a TeamError on failure
+pub async fn remove_player_from_roster(pool: &PgPool, team_id: i64, owner_id: i64, player_id: &PlayerId) -> Result<Team, TeamError> {
+    let mut tx = pool.begin().await?;
+
+    let team = sqlx::query_as!(
+        Team,
+        "SELECT * FROM teams WHERE id = $1 FOR UPDATE",
+        team_id
+    )
+    .fetch_optional(&mut tx)
+    .await?
+    .ok_or(TeamError::NotFound)?;
+
+    if team.owner_id != owner_id {
+        return Err(TeamError::NotAuthorized);
+    }
+    // Owners kicked from the league lose control of their team
+    let league = crate::db::league::get_league_by_id(pool, team.league_id).await?;
+    league.assert_member(owner_id)?;
+    league.assert_can_modify(owner_id)?;
+
+    if !team.roster.iter().any(|id| id == player_id.as_str()) {
+        return Err(TeamError::NotOnRoster);
+    }
+
+    let team = sqlx::query_as!(
+        Team,
+        r#"
+        UPDATE teams
+        SET roster = array_remove(roster, $1)
+        WHERE id = $2
+        RETURNING *
+        "#,
+        player_id.as_str(),
+        team_id
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(team)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn new_team(name: &str) -> NewTeam {
+        NewTeam { name: name.to_string() }
+    }
+
+    #[sqlx::test]
+    async fn participant_can_create_a_team(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+
+        let team = create_team(&pool, league.id, admin.id, new_team("  Baron Stealers ")).await.unwrap();
+        assert_eq!(team.league_id, league.id);
+        assert_eq!(team.owner_id, admin.id);
+        assert_eq!(team.name, "Baron Stealers");
+        assert!(team.roster.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn second_team_in_the_same_league_is_rejected(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        create_team(&pool, league.id, admin.id, new_team("Baron Stealers")).await.unwrap();
+
+        let result = create_team(&pool, league.id, admin.id, new_team("Dragon Soul")).await;
+        assert!(matches!(result, Err(TeamError::AlreadyHasTeam)));
+    }
+
+
+    const PLAYER_HEX: &str = "65a1b2c3d4e5f60718293a4b";
+
+    fn player(position: Option<&str>) -> ProPlayer {
+        let mut document = mongodb::bson::doc! { "_id": mongodb::bson::oid::ObjectId::new() };
+        if let Some(position) = position {
+            document.insert("position", position);
+        }
+        mongodb::bson::from_document(document).unwrap()
+    }
+
+    #[test]
+    fn player_already_on_the_roster_is_rejected() {
+        let player_id = PlayerId::parse(PLAYER_HEX).unwrap();
+        let roster = vec![PLAYER_HEX.to_string()];
+        assert!(matches!(check_roster_space(&roster, &player_id, 5), Err(TeamError::AlreadyOnRoster)));
+    }
+
+    #[test]
+    fn full_roster_is_rejected() {
+        let player_id = PlayerId::parse(PLAYER_HEX).unwrap();
+        let roster: Vec<String> = (0..5).map(|_| mongodb::bson::oid::ObjectId::new().to_hex()).collect();
+        assert!(matches!(check_roster_space(&roster, &player_id, 5), Err(TeamError::RosterFull)));
+        assert!(check_roster_space(&roster[..4], &player_id, 5).is_ok());
+    }
+
+    #[test]
+    fn nonexistent_player_is_rejected() {
+        assert!(matches!(check_position_open(None, &[]), Err(TeamError::PlayerNotFound)));
+    }
+
+    #[test]
+    fn filled_position_is_rejected() {
+        let roster_players = vec![player(Some("MID")), player(Some("Top"))];
+        assert!(matches!(check_position_open(Some(&player(Some("Mid"))), &roster_players), Err(TeamError::PositionFilled)));
+        assert!(check_position_open(Some(&player(Some("ADC"))), &roster_players).is_ok());
+    }
+
+    #[test]
+    fn player_without_a_known_position_is_rejected() {
+        for position in [None, Some(""), Some("Coach")] {
+            assert!(matches!(check_position_open(Some(&player(position)), &[]), Err(TeamError::UnknownPosition)));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,54 @@
+use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use crate::errors::UserError;
+
+/// Revokes a token, given its id and `exp` claim, so guards reject it from now on.
+/// The row is kept until the token is also past its refresh grace window, since
+/// `RefreshGuard` still accepts it until then. Returns false if it was already revoked.
+pub async fn revoke_token(pool: &PgPool, jti: &str, token_exp: usize) -> Result<bool, UserError> {
+    let keep_until = token_exp as i64 + crate::auth::REFRESH_GRACE_SECONDS as i64;
+    let expires_at = DateTime::<Utc>::from_timestamp(keep_until, 0)
+        .ok_or_else(|| UserError::ValidationError("Token expiry is out of range".to_string()))?;
+
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO revoked_tokens (jti, expires_at)
+        VALUES ($1, $2)
+        ON CONFLICT (jti) DO NOTHING
+        "#,
+        jti,
+        expires_at
+    )
+    .execute(pool)
+    .await
+    .map_err(UserError::DatabaseError)?;
+    Ok(result.rows_affected() == 1)
+}
+
+/// Checks that a token is still usable: it hasn't been revoked and its user's
+/// account hasn't been closed
+pub async fn is_session_active(pool: &PgPool, jti: &str, user_id: i64) -> Result<bool, UserError> {
+    let active = sqlx::query!(
+        r#"
+        SELECT
+            NOT EXISTS(SELECT 1 FROM revoked_tokens WHERE jti = $1)
+            AND EXISTS(SELECT 1 FROM users WHERE id = $2 AND deleted_at IS NULL) as "active!"
+        "#,
+        jti,
+        user_id
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(UserError::DatabaseError)?
+    .active;
+    Ok(active)
+}
+
+/// Deletes revocations for tokens that have expired on their own, returning how many were removed
+pub async fn purge_expired_revocations(pool: &PgPool) -> Result<u64, UserError> {
+    let result = sqlx::query!("DELETE FROM revoked_tokens WHERE expires_at < CURRENT_TIMESTAMP")
+        .execute(pool)
+        .await
+        .map_err(UserError::DatabaseError)?;
+    Ok(result.rows_affected())
+}
\ No newline at end of file
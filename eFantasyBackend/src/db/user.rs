@@ -1,14 +1,21 @@
-use sqlx::PgPool;
-use crate::models::user::{User, NewUser, UserProfileUpdate, ProfileCompletion, UserStats};
+use std::collections::btree_map::{BTreeMap, Entry};
+use sqlx::{PgPool, Postgres, Transaction};
+use crate::models::user::{User, NewUser, UserProfileUpdate, ProfileCompletion, UserStats, StatDelta};
 use crate::errors::UserError;
 
 /// Creates a new user in the database
 pub async fn create_user(pool: &PgPool, user: NewUser) -> Result<User, UserError> {
+    crate::validation::validate_password_strength(&user.password)?;
+    let email = crate::validation::normalize_email(&user.email);
+    if !crate::validation::is_valid_email(&email) {
+        return Err(UserError::InvalidEmail);
+    }
+
     // Check if user already exists
     let user_exists = sqlx::query!(
-        "SELECT EXISTS(SELECT 1 FROM users WHERE username = $1 OR email = $2) as exists",
+        "SELECT EXISTS(SELECT 1 FROM users WHERE username = $1 OR LOWER(email) = $2) as exists",
         user.username,
-        user.email,
+        email,
     )
     .fetch_one(pool)
     .await
@@ -29,19 +36,23 @@ pub async fn create_user(pool: &PgPool, user: NewUser) -> Result<User, UserError
         RETURNING *
         "#,
         user.username,
-        user.email,
+        email,
         hashed_password
     )
     .fetch_one(pool)
     .await
-    .map_err(UserError::DatabaseError)
+    .map_err(|e| match e {
+        // A concurrent registration can still hit the unique indexes
+        sqlx::Error::Database(ref db_err) if db_err.code().as_deref() == Some("23505") => UserError::AlreadyExists,
+        _ => UserError::DatabaseError(e),
+    })
 }
 
-/// Retrieves a user by their ID
+/// Retrieves a user by their ID, ignoring closed accounts
 pub async fn get_user_by_id(pool: &PgPool, user_id: i64) -> Result<User, UserError> {
     sqlx::query_as!(
         User,
-        "SELECT * FROM users WHERE id = $1",
+        "SELECT * FROM users WHERE id = $1 AND deleted_at IS NULL",
         user_id
     )
     .fetch_one(pool)
@@ -52,11 +63,11 @@ pub async fn get_user_by_id(pool: &PgPool, user_id: i64) -> Result<User, UserErr
     })
 }
 
-/// Retrieves a user by their username
+/// Retrieves a user by their username, ignoring closed accounts
 pub async fn get_user_by_name(pool: &PgPool, user_name: &str) -> Result<User, UserError> {
     sqlx::query_as!(
         User,
-        "SELECT * FROM users WHERE username = $1",
+        "SELECT * FROM users WHERE username = $1 AND deleted_at IS NULL",
         user_name
     )
     .fetch_one(pool)
@@ -67,6 +78,28 @@ pub async fn get_user_by_name(pool: &PgPool, user_name: &str) -> Result<User, Us
     })
 }
 
+/// Replaces a user's password hash, recording it as produced by the current hash settings
+pub async fn update_user_password(pool: &PgPool, user_id: i64, hash: &str) -> Result<(), UserError> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE users
+        SET password = $1, hash_version = $2, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $3
+        "#,
+        hash,
+        crate::auth::CURRENT_HASH_VERSION,
+        user_id
+    )
+    .execute(pool)
+    .await
+    .map_err(UserError::DatabaseError)?;
+
+    if result.rows_affected() == 0 {
+        return Err(UserError::NotFound);
+    }
+    Ok(())
+}
+
 /// Updates a user's profile
 pub async fn update_user_profile(
     pool: &PgPool,
@@ -81,13 +114,15 @@ pub async fn update_user_profile(
             nickname = COALESCE($1, nickname),
             bio = COALESCE($2, bio),
             avatar_url = COALESCE($3, avatar_url),
+            timezone = COALESCE($4, timezone),
             updated_at = CURRENT_TIMESTAMP
-        WHERE id = $4
+        WHERE id = $5
         RETURNING *
         "#,
         profile_update.nickname,
         profile_update.bio,
         profile_update.avatar_url,
+        profile_update.timezone,
         user_id
     )
     .fetch_one(pool)
@@ -129,52 +164,141 @@ pub async fn complete_profile(pool: &PgPool, user_id: i64, profile: ProfileCompl
     })
 }
 
-/// Deletes a user from the database
-pub async fn delete_user(pool: &PgPool, user_id: i64) -> Result<bool, UserError> {
+/// Closes a user's own account
+///
+/// Fails with `UserError::MustTransferLeagues` if the user still admins a league
+/// with other participants. Leagues where they are the only participant are
+/// deleted, they are removed from every other league, and their row is soft-deleted.
+pub async fn close_account(pool: &PgPool, user_id: i64) -> Result<(), UserError> {
+    let mut tx = pool.begin().await.map_err(UserError::DatabaseError)?;
+
+    let admins_shared_league = sqlx::query!(
+        r#"
+        SELECT EXISTS(
+            SELECT 1 FROM leagues
+            WHERE admin_id = $1 AND cardinality(array_remove(participants, $1)) > 0
+        ) as "exists!"
+        "#,
+        user_id
+    )
+    .fetch_one(&mut tx)
+    .await
+    .map_err(UserError::DatabaseError)?
+    .exists;
+
+    if admins_shared_league {
+        return Err(UserError::MustTransferLeagues);
+    }
+
+    // Any league they still admin has no one else in it
+    sqlx::query!("DELETE FROM leagues WHERE admin_id = $1", user_id)
+        .execute(&mut tx)
+        .await
+        .map_err(UserError::DatabaseError)?;
+
+    sqlx::query!(
+        r#"
+        UPDATE leagues
+        SET participants = array_remove(participants, $1), draft_order = array_remove(draft_order, $1), updated_at = CURRENT_TIMESTAMP
+        WHERE $1 = ANY(participants)
+        "#,
+        user_id
+    )
+    .execute(&mut tx)
+    .await
+    .map_err(UserError::DatabaseError)?;
+
+    sqlx::query!("DELETE FROM teams WHERE owner_id = $1", user_id)
+        .execute(&mut tx)
+        .await
+        .map_err(UserError::DatabaseError)?;
+
     let result = sqlx::query!(
-        "DELETE FROM users WHERE id = $1",
+        r#"
+        UPDATE users
+        SET deleted_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+        WHERE id = $1 AND deleted_at IS NULL
+        "#,
         user_id
     )
-    .execute(pool)
+    .execute(&mut tx)
     .await
     .map_err(UserError::DatabaseError)?;
 
-    Ok(result.rows_affected() > 0)
+    if result.rows_affected() == 0 {
+        return Err(UserError::NotFound);
+    }
+
+    tx.commit().await.map_err(UserError::DatabaseError)
 }
 
-/// Updates a user's statistics
-pub async fn update_user_stats(
-    pool: &PgPool,
-    user_id: i64,
-    wins: i32,
-    losses: i32,
-    ties: i32,
-    points: f64
-) -> Result<User, UserError> {
-    sqlx::query_as!(
-        User,
+/// Applies several users' stat changes in a single multi-row update.
+///
+/// Runs inside the caller's transaction so the whole batch commits or rolls back
+/// together. Deltas for the same user are summed first. Returns
+/// `UserError::NotFound` if any user in the batch doesn't exist, and
+/// `UserError::ValidationError` if summing a user's deltas overflows.
+///
+/// Not exposed through any route: only the week-finalize step, which computes the
+/// deltas itself, should call it once it exists.
+#[allow(dead_code)]
+pub async fn batch_update_user_stats(
+    tx: &mut Transaction<'_, Postgres>,
+    deltas: Vec<StatDelta>
+) -> Result<(), UserError> {
+    let overflow = || UserError::ValidationError("Stat deltas overflow".to_string());
+    let mut merged: BTreeMap<i64, StatDelta> = BTreeMap::new();
+    for delta in deltas {
+        match merged.entry(delta.user_id) {
+            Entry::Vacant(entry) => {
+                entry.insert(delta);
+            }
+            Entry::Occupied(mut entry) => {
+                let existing = entry.get_mut();
+                existing.wins = existing.wins.checked_add(delta.wins).ok_or_else(overflow)?;
+                existing.losses = existing.losses.checked_add(delta.losses).ok_or_else(overflow)?;
+                existing.ties = existing.ties.checked_add(delta.ties).ok_or_else(overflow)?;
+                existing.points += delta.points;
+            }
+        }
+    }
+    if merged.is_empty() {
+        return Ok(());
+    }
+
+    let user_ids: Vec<i64> = merged.keys().copied().collect();
+    let wins: Vec<i32> = merged.values().map(|d| d.wins).collect();
+    let losses: Vec<i32> = merged.values().map(|d| d.losses).collect();
+    let ties: Vec<i32> = merged.values().map(|d| d.ties).collect();
+    let points: Vec<f64> = merged.values().map(|d| d.points).collect();
+
+    let result = sqlx::query!(
         r#"
-        UPDATE users
+        UPDATE users AS u
         SET 
-            wins = wins + $1,
-            losses = losses + $2,
-            ties = ties + $3,
-            total_points = total_points + $4
-        WHERE id = $5
-        RETURNING *
+            wins = u.wins + d.wins,
+            losses = u.losses + d.losses,
+            ties = u.ties + d.ties,
+            total_points = u.total_points + d.points,
+            updated_at = CURRENT_TIMESTAMP
+        FROM UNNEST($1::BIGINT[], $2::INT[], $3::INT[], $4::INT[], $5::FLOAT8[])
+            AS d(user_id, wins, losses, ties, points)
+        WHERE u.id = d.user_id
         "#,
-        wins,
-        losses,
-        ties,
-        points,
-        user_id
+        &user_ids,
+        &wins,
+        &losses,
+        &ties,
+        &points
     )
-    .fetch_one(pool)
+    .execute(&mut *tx)
     .await
-    .map_err(|e| match e {
-        sqlx::Error::RowNotFound => UserError::NotFound,
-        _ => UserError::DatabaseError(e),
-    })
+    .map_err(UserError::DatabaseError)?;
+
+    if result.rows_affected() != user_ids.len() as u64 {
+        return Err(UserError::NotFound);
+    }
+    Ok(())
 }
 
 /// Retrieves a user's statistics
@@ -185,7 +309,8 @@ pub async fn get_user_statistics(pool: &PgPool, user_id: i64) -> Result<UserStat
             COALESCE(wins, 0) as "wins!: i32",
             COALESCE(losses, 0) as "losses!: i32",
             COALESCE(ties, 0) as "ties!: i32",
-            COALESCE(total_points, 0.0) as "total_points!: f64"
+            COALESCE(total_points, 0.0) as "total_points!: f64",
+            (SELECT COUNT(*) FROM teams WHERE owner_id = users.id)::INT as "teams_created!: i32"
         FROM users
         WHERE id = $1
         "#,
@@ -204,6 +329,93 @@ pub async fn get_user_statistics(pool: &PgPool, user_id: i64) -> Result<UserStat
         ties: row.ties,
         total_points: row.total_points,
         leagues_joined: 0,  // Placeholder value
-        teams_created: 0,   // Placeholder value
+        teams_created: row.teams_created,
     })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support;
+
+    fn delta(user_id: i64, wins: i32, losses: i32, points: f64) -> StatDelta {
+        StatDelta { user_id, wins, losses, ties: 0, points }
+    }
+
+    #[sqlx::test]
+    async fn batch_update_applies_every_delta(pool: PgPool) {
+        let alice = test_support::user(&pool, "alice").await;
+        let bob = test_support::user(&pool, "bob").await;
+
+        let mut tx = pool.begin().await.unwrap();
+        batch_update_user_stats(&mut tx, vec![
+            delta(alice.id, 1, 0, 10.5),
+            delta(bob.id, 0, 1, 4.0),
+            delta(alice.id, 1, 1, 2.0),
+        ]).await.unwrap();
+        tx.commit().await.unwrap();
+
+        let alice_stats = get_user_statistics(&pool, alice.id).await.unwrap();
+        assert_eq!((alice_stats.wins, alice_stats.losses, alice_stats.total_points), (2, 1, 12.5));
+        let bob_stats = get_user_statistics(&pool, bob.id).await.unwrap();
+        assert_eq!((bob_stats.wins, bob_stats.losses, bob_stats.total_points), (0, 1, 4.0));
+    }
+
+    #[sqlx::test]
+    async fn batch_update_rolls_back_entirely_on_failure(pool: PgPool) {
+        let alice = test_support::user(&pool, "alice").await;
+
+        let mut tx = pool.begin().await.unwrap();
+        let result = batch_update_user_stats(&mut tx, vec![
+            delta(alice.id, 1, 0, 10.0),
+            delta(alice.id + 1000, 1, 0, 10.0),
+        ]).await;
+        assert!(matches!(result, Err(UserError::NotFound)));
+        drop(tx);
+
+        let stats = get_user_statistics(&pool, alice.id).await.unwrap();
+        assert_eq!((stats.wins, stats.total_points), (0, 0.0));
+    }
+
+    #[sqlx::test]
+    async fn batch_update_rejects_overflowing_deltas(pool: PgPool) {
+        let alice = test_support::user(&pool, "alice").await;
+
+        let mut tx = pool.begin().await.unwrap();
+        let result = batch_update_user_stats(&mut tx, vec![
+            delta(alice.id, i32::MAX, 0, 0.0),
+            delta(alice.id, 1, 0, 0.0),
+        ]).await;
+        assert!(matches!(result, Err(UserError::ValidationError(_))));
+    }
+
+    #[sqlx::test]
+    async fn admin_must_transfer_a_shared_league_before_closing(pool: PgPool) {
+        let alice = test_support::user(&pool, "alice").await;
+        let bob = test_support::user(&pool, "bob").await;
+        let league = test_support::league(&pool, alice.id, true).await;
+        crate::db::league::join_league(&pool, league.id, bob.id, None).await.unwrap();
+
+        let result = close_account(&pool, alice.id).await;
+        assert!(matches!(result, Err(UserError::MustTransferLeagues)));
+        assert!(get_user_by_id(&pool, alice.id).await.is_ok());
+
+        crate::db::league::transfer_admin(&pool, league.id, alice.id, bob.id).await.unwrap();
+        close_account(&pool, alice.id).await.unwrap();
+        assert!(matches!(get_user_by_id(&pool, alice.id).await, Err(UserError::NotFound)));
+        let league = crate::db::league::get_league_by_id(&pool, league.id).await.unwrap();
+        assert_eq!(league.participants, vec![bob.id]);
+    }
+
+    #[sqlx::test]
+    async fn closing_an_account_deletes_leagues_only_they_were_in(pool: PgPool) {
+        let alice = test_support::user(&pool, "alice").await;
+        let league = test_support::league(&pool, alice.id, true).await;
+
+        close_account(&pool, alice.id).await.unwrap();
+        assert!(matches!(
+            crate::db::league::get_league_by_id(&pool, league.id).await,
+            Err(crate::errors::LeagueError::NotFound)
+        ));
+    }
 }
\ No newline at end of file
@@ -2,9 +2,25 @@ use thiserror::Error;
 use rocket::http::Status;
 use rocket::request::Request;
 use rocket::response::{self, Responder, status};
-use jsonwebtoken;
 use serde_json::json;
 use rocket::serde::json::Json;
+use serde::Serialize;
+
+/// An entry in the machine-readable error catalog served at `GET /errors`
+#[derive(Debug, Serialize)]
+pub struct ErrorInfo {
+    pub code: &'static str,
+    pub status: u16,
+    pub message: String,
+}
+
+/// Every error the API can return, grouped by error type
+#[derive(Debug, Serialize)]
+pub struct ErrorCatalog {
+    pub user_errors: Vec<ErrorInfo>,
+    pub league_errors: Vec<ErrorInfo>,
+    pub team_errors: Vec<ErrorInfo>,
+}
 
 /// Custom error types for user-related operations
 #[derive(Error, Debug)]
@@ -19,18 +35,78 @@ pub enum UserError {
     InvalidCredentials,
     #[error("JWT error: {0}")]
     JWTError(#[from] jsonwebtoken::errors::Error),
+    #[error("Weak password: {0}")]
+    WeakPassword(String),
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("User must transfer admin of their leagues first")]
+    MustTransferLeagues,
+    #[error("Invalid email address")]
+    InvalidEmail,
 }
 
-impl<'r> Responder<'r, 'static> for UserError {
-    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
-        let (status, message) = match self {
+impl UserError {
+    /// Stable machine-readable code for this error
+    pub fn code(&self) -> &'static str {
+        match self {
+            UserError::AlreadyExists => "user_already_exists",
+            UserError::NotFound => "user_not_found",
+            UserError::DatabaseError(_) => "user_database_error",
+            UserError::InvalidCredentials => "invalid_credentials",
+            UserError::JWTError(_) => "jwt_error",
+            UserError::WeakPassword(_) => "weak_password",
+            UserError::ValidationError(_) => "user_validation_error",
+            UserError::MustTransferLeagues => "must_transfer_leagues",
+            UserError::InvalidEmail => "invalid_email",
+        }
+    }
+
+    /// HTTP status and client-facing message for this error
+    fn status_and_message(&self) -> (Status, &str) {
+        match self {
             UserError::AlreadyExists => (Status::Conflict, "Username or email already exists"),
             UserError::NotFound => (Status::NotFound, "User not found"),
             UserError::DatabaseError(_) => (Status::InternalServerError, "An internal error occurred"),
             UserError::InvalidCredentials => (Status::Unauthorized, "Invalid credentials"),
             UserError::JWTError(_) => (Status::InternalServerError, "An error occurred with authentication"),
-        };
-        status::Custom(status, message).respond_to(req)
+            UserError::WeakPassword(reason) => (Status::BadRequest, reason.as_str()),
+            UserError::ValidationError(reason) => (Status::BadRequest, reason.as_str()),
+            UserError::MustTransferLeagues => (Status::Conflict, "Transfer admin of your leagues before deleting your account"),
+            UserError::InvalidEmail => (Status::BadRequest, "Invalid email address"),
+        }
+    }
+
+    /// One instance of every variant. Variants carrying a reason use a generic description.
+    fn examples() -> Vec<UserError> {
+        vec![
+            UserError::AlreadyExists,
+            UserError::NotFound,
+            UserError::DatabaseError(sqlx::Error::PoolTimedOut),
+            UserError::InvalidCredentials,
+            UserError::JWTError(jsonwebtoken::errors::ErrorKind::InvalidToken.into()),
+            UserError::WeakPassword("Password does not meet the password policy".to_string()),
+            UserError::ValidationError("A field failed validation".to_string()),
+            UserError::MustTransferLeagues,
+            UserError::InvalidEmail,
+        ]
+    }
+
+    /// Lists every user error with its code, status and message
+    pub fn catalog() -> Vec<ErrorInfo> {
+        Self::examples()
+            .iter()
+            .map(|error| {
+                let (status, message) = error.status_and_message();
+                ErrorInfo { code: error.code(), status: status.code, message: message.to_string() }
+            })
+            .collect()
+    }
+}
+
+impl<'r> Responder<'r, 'static> for UserError {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let (status, message) = self.status_and_message();
+        status::Custom(status, message.to_string()).respond_to(req)
     }
 }
 
@@ -41,17 +117,335 @@ pub enum LeagueError {
     NotFound,
     #[error("Database error: {0}")]
     DatabaseError(#[from] sqlx::Error),
+    #[error("User has already joined this league")]
+    AlreadyJoined,
+    #[error("League is full")]
+    LeagueFull,
+    #[error("Draft has already started")]
+    DraftAlreadyStarted,
+    #[error("Not authorized to perform this action")]
+    NotAuthorized,
+    #[error("Invalid join password")]
+    InvalidJoinPassword,
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("League is locked")]
+    LeagueLocked,
+    #[error("User is not a participant in this league")]
+    NotInLeague,
+    #[error("Invalid max_teams")]
+    InvalidMaxTeams,
+    #[error("Invalid scoring type")]
+    InvalidScoringType,
+    #[error("Invalid league state transition")]
+    InvalidStateTransition,
 }
+
+impl LeagueError {
+    /// Stable machine-readable code for this error
+    pub fn code(&self) -> &'static str {
+        match self {
+            LeagueError::NotFound => "league_not_found",
+            LeagueError::DatabaseError(_) => "league_database_error",
+            LeagueError::AlreadyJoined => "already_joined",
+            LeagueError::LeagueFull => "league_full",
+            LeagueError::DraftAlreadyStarted => "draft_already_started",
+            LeagueError::NotAuthorized => "not_authorized",
+            LeagueError::InvalidJoinPassword => "invalid_join_password",
+            LeagueError::ValidationError(_) => "league_validation_error",
+            LeagueError::LeagueLocked => "league_locked",
+            LeagueError::NotInLeague => "not_in_league",
+            LeagueError::InvalidMaxTeams => "invalid_max_teams",
+            LeagueError::InvalidScoringType => "invalid_scoring_type",
+            LeagueError::InvalidStateTransition => "invalid_state_transition",
+        }
+    }
+
+    /// HTTP status and client-facing message for this error
+    fn status_and_message(&self) -> (Status, &str) {
+        match self {
+            LeagueError::NotFound => (Status::NotFound, "League not found"),
+            LeagueError::DatabaseError(_) => (Status::InternalServerError, "Database error"),
+            LeagueError::AlreadyJoined => (Status::Conflict, "User has already joined this league"),
+            LeagueError::LeagueFull => (Status::BadRequest, "League is full"),
+            LeagueError::DraftAlreadyStarted => (Status::BadRequest, "Draft has already started"),
+            LeagueError::NotAuthorized => (Status::Forbidden, "Not authorized to perform this action"),
+            LeagueError::InvalidJoinPassword => (Status::Unauthorized, "Invalid join password"),
+            LeagueError::ValidationError(reason) => (Status::BadRequest, reason.as_str()),
+            LeagueError::LeagueLocked => (Status::Locked, "League is locked"),
+            LeagueError::NotInLeague => (Status::BadRequest, "User is not a participant in this league"),
+            LeagueError::InvalidMaxTeams => (Status::BadRequest, "max_teams must be between 2 and 100 and at least the number of participants"),
+            LeagueError::InvalidScoringType => (Status::BadRequest, "scoring_type must be one of: standard, ppr, custom"),
+            LeagueError::InvalidStateTransition => (Status::Conflict, "The league can't move to that state from its current one"),
+        }
+    }
+
+    /// One instance of every variant. Variants carrying a reason use a generic description.
+    fn examples() -> Vec<LeagueError> {
+        vec![
+            LeagueError::NotFound,
+            LeagueError::DatabaseError(sqlx::Error::PoolTimedOut),
+            LeagueError::AlreadyJoined,
+            LeagueError::LeagueFull,
+            LeagueError::DraftAlreadyStarted,
+            LeagueError::NotAuthorized,
+            LeagueError::InvalidJoinPassword,
+            LeagueError::ValidationError("A field failed validation".to_string()),
+            LeagueError::LeagueLocked,
+            LeagueError::NotInLeague,
+            LeagueError::InvalidMaxTeams,
+            LeagueError::InvalidScoringType,
+            LeagueError::InvalidStateTransition,
+        ]
+    }
+
+    /// Lists every league error with its code, status and message
+    pub fn catalog() -> Vec<ErrorInfo> {
+        Self::examples()
+            .iter()
+            .map(|error| {
+                let (status, message) = error.status_and_message();
+                ErrorInfo { code: error.code(), status: status.code, message: message.to_string() }
+            })
+            .collect()
+    }
+}
+
 /// Implement Responder for LeagueError to allow it to be returned directly from route handlers
 impl<'r> rocket::response::Responder<'r, 'static> for LeagueError {
     fn respond_to(self, request: &'r rocket::Request<'_>) -> rocket::response::Result<'static> {
-        let (status, error_message) = match self {
-            LeagueError::NotFound => (Status::NotFound, "League not found"),
-            LeagueError::DatabaseError(_) => (Status::InternalServerError, "Database error"),
-        };
+        let (status, error_message) = self.status_and_message();
         // Return a custom error response
         status::Custom(status, Json(json!({
             "error": error_message
         }))).respond_to(request)
     }
+}
+
+/// Represents errors that can occur during team operations
+#[derive(Error, Debug)]
+pub enum TeamError {
+    #[error("Team not found")]
+    NotFound,
+    #[error("Database error: {0}")]
+    DatabaseError(#[from] sqlx::Error),
+    #[error("User already has a team in this league")]
+    AlreadyHasTeam,
+    #[error("Not authorized to perform this action")]
+    NotAuthorized,
+    #[error("Validation error: {0}")]
+    ValidationError(String),
+    #[error("Pro player not found")]
+    PlayerNotFound,
+    #[error("Pro player lookup failed: {0}")]
+    PlayerLookupFailed(String),
+    #[error("Roster is full")]
+    RosterFull,
+    #[error("Player is already on the roster")]
+    AlreadyOnRoster,
+    #[error("Player is not on the roster")]
+    NotOnRoster,
+    #[error("Roster already has a player at this position")]
+    PositionFilled,
+    #[error("Player has no known position")]
+    UnknownPosition,
+    /// A check on the team's league failed (not found, not a member, locked, ...)
+    #[error(transparent)]
+    League(#[from] LeagueError),
+}
+
+impl TeamError {
+    /// Stable machine-readable code for this error
+    pub fn code(&self) -> &'static str {
+        match self {
+            TeamError::NotFound => "team_not_found",
+            TeamError::DatabaseError(_) => "team_database_error",
+            TeamError::AlreadyHasTeam => "already_has_team",
+            TeamError::NotAuthorized => "team_not_authorized",
+            TeamError::ValidationError(_) => "team_validation_error",
+            TeamError::PlayerNotFound => "pro_player_not_found",
+            TeamError::PlayerLookupFailed(_) => "pro_player_lookup_failed",
+            TeamError::RosterFull => "roster_full",
+            TeamError::AlreadyOnRoster => "already_on_roster",
+            TeamError::NotOnRoster => "not_on_roster",
+            TeamError::PositionFilled => "position_filled",
+            TeamError::UnknownPosition => "unknown_position",
+            TeamError::League(error) => error.code(),
+        }
+    }
+
+    /// HTTP status and client-facing message for this error
+    fn status_and_message(&self) -> (Status, &str) {
+        match self {
+            TeamError::NotFound => (Status::NotFound, "Team not found"),
+            TeamError::DatabaseError(_) => (Status::InternalServerError, "Database error"),
+            TeamError::AlreadyHasTeam => (Status::Conflict, "User already has a team in this league"),
+            TeamError::NotAuthorized => (Status::Forbidden, "Only the team owner can do this"),
+            TeamError::ValidationError(reason) => (Status::BadRequest, reason.as_str()),
+            TeamError::PlayerNotFound => (Status::NotFound, "Pro player not found"),
+            TeamError::PlayerLookupFailed(_) => (Status::InternalServerError, "An internal error occurred"),
+            TeamError::RosterFull => (Status::BadRequest, "Roster is full"),
+            TeamError::AlreadyOnRoster => (Status::Conflict, "Player is already on the roster"),
+            TeamError::NotOnRoster => (Status::NotFound, "Player is not on the roster"),
+            TeamError::PositionFilled => (Status::Conflict, "Roster already has a player at this position"),
+            TeamError::UnknownPosition => (Status::BadRequest, "Player has no known position (TOP, JUNGLE, MID, ADC or SUPPORT)"),
+            TeamError::League(error) => error.status_and_message(),
+        }
+    }
+
+    /// One instance of every variant. Variants carrying a reason use a generic description;
+    /// wrapped league errors are listed with the league errors.
+    fn examples() -> Vec<TeamError> {
+        vec![
+            TeamError::NotFound,
+            TeamError::DatabaseError(sqlx::Error::PoolTimedOut),
+            TeamError::AlreadyHasTeam,
+            TeamError::NotAuthorized,
+            TeamError::ValidationError("A field failed validation".to_string()),
+            TeamError::PlayerNotFound,
+            TeamError::PlayerLookupFailed("Lookup failed".to_string()),
+            TeamError::RosterFull,
+            TeamError::AlreadyOnRoster,
+            TeamError::NotOnRoster,
+            TeamError::PositionFilled,
+            TeamError::UnknownPosition,
+        ]
+    }
+
+    /// Lists every team error with its code, status and message
+    pub fn catalog() -> Vec<ErrorInfo> {
+        Self::examples()
+            .iter()
+            .map(|error| {
+                let (status, message) = error.status_and_message();
+                ErrorInfo { code: error.code(), status: status.code, message: message.to_string() }
+            })
+            .collect()
+    }
+}
+
+impl<'r> Responder<'r, 'static> for TeamError {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
+        let (status, error_message) = self.status_and_message();
+        status::Custom(status, Json(json!({
+            "error": error_message
+        }))).respond_to(req)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    // Each match is exhaustive, so adding a variant doesn't compile until it gets the next
+    // position here and the variant count is bumped; the tests then fail until it is also
+    // added to `examples`.
+
+    const USER_VARIANTS: usize = 9;
+
+    fn user_position(error: &UserError) -> usize {
+        match error {
+            UserError::AlreadyExists => 0,
+            UserError::NotFound => 1,
+            UserError::DatabaseError(_) => 2,
+            UserError::InvalidCredentials => 3,
+            UserError::JWTError(_) => 4,
+            UserError::WeakPassword(_) => 5,
+            UserError::ValidationError(_) => 6,
+            UserError::MustTransferLeagues => 7,
+            UserError::InvalidEmail => 8,
+        }
+    }
+
+    const LEAGUE_VARIANTS: usize = 13;
+
+    fn league_position(error: &LeagueError) -> usize {
+        match error {
+            LeagueError::NotFound => 0,
+            LeagueError::DatabaseError(_) => 1,
+            LeagueError::AlreadyJoined => 2,
+            LeagueError::LeagueFull => 3,
+            LeagueError::DraftAlreadyStarted => 4,
+            LeagueError::NotAuthorized => 5,
+            LeagueError::InvalidJoinPassword => 6,
+            LeagueError::ValidationError(_) => 7,
+            LeagueError::LeagueLocked => 8,
+            LeagueError::NotInLeague => 9,
+            LeagueError::InvalidMaxTeams => 10,
+            LeagueError::InvalidScoringType => 11,
+            LeagueError::InvalidStateTransition => 12,
+        }
+    }
+
+    /// `League` is left out: wrapped league errors are listed with the league errors
+    const TEAM_VARIANTS: usize = 12;
+
+    fn team_position(error: &TeamError) -> Option<usize> {
+        let position = match error {
+            TeamError::NotFound => 0,
+            TeamError::DatabaseError(_) => 1,
+            TeamError::AlreadyHasTeam => 2,
+            TeamError::NotAuthorized => 3,
+            TeamError::ValidationError(_) => 4,
+            TeamError::PlayerNotFound => 5,
+            TeamError::PlayerLookupFailed(_) => 6,
+            TeamError::RosterFull => 7,
+            TeamError::AlreadyOnRoster => 8,
+            TeamError::NotOnRoster => 9,
+            TeamError::PositionFilled => 10,
+            TeamError::UnknownPosition => 11,
+            TeamError::League(_) => return None,
+        };
+        Some(position)
+    }
+
+    #[test]
+    fn examples_cover_every_variant() {
+        let users: HashSet<usize> = UserError::examples().iter().map(user_position).collect();
+        assert_eq!(users, (0..USER_VARIANTS).collect());
+
+        let leagues: HashSet<usize> = LeagueError::examples().iter().map(league_position).collect();
+        assert_eq!(leagues, (0..LEAGUE_VARIANTS).collect());
+
+        let teams: HashSet<usize> = TeamError::examples().iter().filter_map(team_position).collect();
+        assert_eq!(teams, (0..TEAM_VARIANTS).collect());
+    }
+
+    #[test]
+    fn catalog_codes_are_unique() {
+        let codes: Vec<&str> = UserError::catalog()
+            .into_iter()
+            .chain(LeagueError::catalog())
+            .chain(TeamError::catalog())
+            .map(|info| info.code)
+            .collect();
+        let unique: HashSet<&str> = codes.iter().copied().collect();
+        assert_eq!(unique.len(), codes.len(), "duplicate codes in {:?}", codes);
+    }
+
+    #[test]
+    fn wrapped_league_errors_keep_their_code() {
+        let error = TeamError::from(LeagueError::LeagueLocked);
+        assert_eq!(error.code(), "league_locked");
+        assert!(LeagueError::catalog().iter().any(|info| info.code == error.code()));
+    }
+
+
+    #[get("/full")]
+    fn full_league() -> Result<&'static str, LeagueError> {
+        Err(LeagueError::LeagueFull)
+    }
+
+    #[rocket::async_test]
+    async fn league_full_responds_with_400_and_a_json_error() {
+        let rocket = rocket::build().mount("/", routes![full_league]);
+        let client = rocket::local::asynchronous::Client::tracked(rocket).await.expect("valid rocket instance");
+
+        let response = client.get("/full").dispatch().await;
+        assert_eq!(response.status(), Status::BadRequest);
+        assert_eq!(response.content_type(), Some(rocket::http::ContentType::JSON));
+        let body = response.into_json::<serde_json::Value>().await.unwrap();
+        assert_eq!(body, json!({ "error": "League is full" }));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,111 @@
+use std::time::Instant;
+use rocket::fairing::{Fairing, Info, Kind};
+use rocket::{Data, Request, Response};
+
+/// Header a client sends (with value "true") to ask for timing data, and that the
+/// response carries it back in
+const DEBUG_TIMING_HEADER: &str = "X-Debug-Timing";
+
+/// Time at which Rocket started handling a request, cached per request
+#[derive(Clone, Copy)]
+struct RequestStart(Option<Instant>);
+
+/// Development-only fairing that reports how long a request took.
+///
+/// When enabled via DEBUG_TIMING=true, requests sending `X-Debug-Timing: true`
+/// get an `X-Debug-Timing: total_ms=<ms>` response header.
+pub struct DebugTiming {
+    enabled: bool,
+}
+
+impl DebugTiming {
+    /// Reads the DEBUG_TIMING flag; timing is off unless it is "true"
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("DEBUG_TIMING")
+            .map(|value| value.trim().eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        if enabled {
+            println!("Debug request timing enabled");
+        }
+        DebugTiming { enabled }
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for DebugTiming {
+    fn info(&self) -> Info {
+        Info {
+            name: "Debug request timing",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _data: &mut Data<'_>) {
+        if self.enabled {
+            request.local_cache(|| RequestStart(Some(Instant::now())));
+        }
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+        if !self.enabled {
+            return;
+        }
+
+        let requested = request
+            .headers()
+            .get_one(DEBUG_TIMING_HEADER)
+            .map(|value| value.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        if !requested {
+            return;
+        }
+
+        if let RequestStart(Some(start)) = request.local_cache(|| RequestStart(None)) {
+            let total_ms = start.elapsed().as_secs_f64() * 1000.0;
+            response.set_raw_header(DEBUG_TIMING_HEADER, format!("total_ms={:.3}", total_ms));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rocket::http::{Header, Status};
+    use rocket::local::asynchronous::Client;
+
+    #[get("/ping")]
+    fn ping() -> &'static str {
+        "pong"
+    }
+
+    async fn client(enabled: bool) -> Client {
+        let rocket = rocket::build()
+            .attach(DebugTiming { enabled })
+            .mount("/", routes![ping]);
+        Client::tracked(rocket).await.expect("valid rocket instance")
+    }
+
+    async fn timing_header(client: &Client, requested: bool) -> Option<String> {
+        let mut request = client.get("/ping");
+        if requested {
+            request = request.header(Header::new(DEBUG_TIMING_HEADER, "true"));
+        }
+        let response = request.dispatch().await;
+        assert_eq!(response.status(), Status::Ok);
+        response.headers().get_one(DEBUG_TIMING_HEADER).map(str::to_string)
+    }
+
+    #[rocket::async_test]
+    async fn timing_header_appears_when_requested_in_dev_mode() {
+        let client = client(true).await;
+        let header = timing_header(&client, true).await.expect("timing header");
+        assert!(header.starts_with("total_ms="), "{}", header);
+        assert_eq!(timing_header(&client, false).await, None);
+    }
+
+    #[rocket::async_test]
+    async fn timing_header_is_never_sent_when_disabled() {
+        let client = client(false).await;
+        assert_eq!(timing_header(&client, true).await, None);
+    }
+}
\ No newline at end of file
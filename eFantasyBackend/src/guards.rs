@@ -1,10 +1,15 @@
 use rocket::request::{FromRequest, Outcome};
 use rocket::http::Status;
-use rocket::Request;
+use rocket::{Request, State};
 use crate::auth;
+use crate::AppState;
 /// Guard for authenticated routes
 pub struct AuthGuard {
     pub user_id: i64,
+    /// Expiry of the presented token, in seconds since the epoch
+    pub expires_at: usize,
+    /// Id of the presented token, used to revoke it on sign out
+    pub jti: String,
 }
 
 #[rocket::async_trait]
@@ -12,28 +17,26 @@ impl<'r> FromRequest<'r> for AuthGuard {
     type Error = ();
 
     async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
-        println!("AuthGuard: Checking for Authorization header");
-
         if let Some(auth_header) = request.headers().get_one("Authorization") {
             let token = auth_header
                 .trim_start_matches("Bearer ")
                 .trim()
                 .trim_matches('"');  // This line removes surrounding quotes
-            
-            println!("AuthGuard: Token received: {}", token);
 
-            match auth::validate_token(token) {
-                Ok(user_id) => {
-                    println!("AuthGuard: Token validated successfully for user_id: {}", user_id);
-                    Outcome::Success(AuthGuard { user_id })
+            match auth::validate_token_details(token) {
+                Ok(validated) => {
+                    if let Err(status) = check_session(request, &validated).await {
+                        return Outcome::Error((status, ()));
+                    }
+                    Outcome::Success(AuthGuard {
+                        user_id: validated.user_id,
+                        expires_at: validated.expires_at,
+                        jti: validated.jti,
+                    })
                 },
-                Err(e) => {
-                    println!("AuthGuard: Token validation failed: {:?}", e);
-                    Outcome::Error((Status::Unauthorized, ()))
-                }
+                Err(_) => Outcome::Error((Status::Unauthorized, ())),
             }
         } else {
-            println!("AuthGuard: No Authorization header found");
             Outcome::Error((Status::Unauthorized, ()))
         }
     }
@@ -51,12 +54,69 @@ impl<'r> FromRequest<'r> for NoAuthGuard {
             let token = auth_header.trim_start_matches("Bearer ")
                                     .trim()
                                     .trim_matches('"');
-            match crate::auth::validate_token(token) {
-                Ok(_) => Outcome::Error((Status::Forbidden, ())),
+            match crate::auth::validate_token_details(token) {
+                // A signed-out token, or one for a closed account, no longer counts as being logged in
+                Ok(validated) => match check_session(request, &validated).await {
+                    Ok(()) => Outcome::Error((Status::Forbidden, ())),
+                    Err(status) if status == Status::Unauthorized => Outcome::Success(NoAuthGuard),
+                    Err(status) => Outcome::Error((status, ())),
+                },
                 Err(_) => Outcome::Success(NoAuthGuard),
             }
         } else {
             Outcome::Success(NoAuthGuard)
         }
     }
+}
+
+/// Guard for the token refresh route. Accepts tokens that expired within the
+/// refresh grace window, which `AuthGuard` would reject.
+pub struct RefreshGuard {
+    pub user_id: i64,
+    /// Expiry of the presented token, in seconds since the epoch
+    pub expires_at: usize,
+    /// Id of the presented token, revoked once it has been exchanged
+    pub jti: String,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RefreshGuard {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        if let Some(auth_header) = request.headers().get_one("Authorization") {
+            let token = auth_header.trim_start_matches("Bearer ")
+                                    .trim()
+                                    .trim_matches('"');
+            match auth::validate_token_for_refresh(token) {
+                Ok(validated) => match check_session(request, &validated).await {
+                    Ok(()) => Outcome::Success(RefreshGuard {
+                        user_id: validated.user_id,
+                        expires_at: validated.expires_at,
+                        jti: validated.jti,
+                    }),
+                    Err(status) => Outcome::Error((status, ())),
+                },
+                Err(_) => Outcome::Error((Status::Unauthorized, ())),
+            }
+        } else {
+            Outcome::Error((Status::Unauthorized, ()))
+        }
+    }
+}
+
+/// Rejects tokens that were revoked by signing out or whose account has been closed
+async fn check_session(request: &Request<'_>, validated: &auth::ValidatedToken) -> Result<(), Status> {
+    let state = match request.guard::<&State<AppState>>().await {
+        Outcome::Success(state) => state,
+        _ => return Err(Status::InternalServerError),
+    };
+    match crate::db::token::is_session_active(&state.db, &validated.jti, validated.user_id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(Status::Unauthorized),
+        Err(e) => {
+            eprintln!("Session check failed: {}", e);
+            Err(Status::InternalServerError)
+        }
+    }
 }
\ No newline at end of file
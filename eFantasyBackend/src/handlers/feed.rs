@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use rocket::State;
+use rocket::serde::json::Json;
+use crate::AppState;
+use crate::models::feed::{FeedItem, FeedPage};
+use crate::errors::LeagueError;
+
+/// Default number of feed items per page
+const DEFAULT_FEED_LIMIT: i64 = 20;
+/// Maximum number of feed items per page
+const MAX_FEED_LIMIT: i64 = 100;
+
+/// How long a feed page is served from the cache before it is rebuilt
+const FEED_CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// Briefly caches feed pages so a busy landing page doesn't query the database on
+/// every request. Pages are keyed by `(limit, offset)`.
+#[derive(Debug, Default)]
+pub struct FeedCache {
+    pages: Mutex<HashMap<(i64, i64), (Instant, FeedPage)>>,
+}
+
+impl FeedCache {
+    /// Returns the cached page if it is still fresh
+    fn get(&self, limit: i64, offset: i64) -> Option<FeedPage> {
+        let pages = self.pages.lock().unwrap();
+        pages
+            .get(&(limit, offset))
+            .filter(|(cached_at, _)| cached_at.elapsed() < FEED_CACHE_TTL)
+            .map(|(_, page)| page.clone())
+    }
+
+    /// Stores a page, dropping expired ones so the cache can't grow without bound
+    fn insert(&self, page: FeedPage) {
+        let mut pages = self.pages.lock().unwrap();
+        pages.retain(|_, (cached_at, _)| cached_at.elapsed() < FEED_CACHE_TTL);
+        pages.insert((page.limit, page.offset), (Instant::now(), page));
+    }
+}
+
+/// Handler for the public activity feed
+///
+/// Only public league activity is included; private leagues never appear. Pages
+/// are cached for `FEED_CACHE_TTL`, so new leagues can take that long to show up.
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `limit` - The page size (defaults to 20, capped at 100)
+/// * `offset` - The number of items to skip
+///
+/// # Returns
+///
+/// Returns a page of feed items, newest first
+#[get("/feed?<limit>&<offset>")]
+pub async fn get_feed(state: &State<AppState>, limit: Option<i64>, offset: Option<i64>) -> Result<Json<FeedPage>, LeagueError> {
+    let limit = limit.unwrap_or(DEFAULT_FEED_LIMIT).clamp(1, MAX_FEED_LIMIT);
+    let offset = offset.unwrap_or(0).max(0);
+    if let Some(page) = state.feed_cache.get(limit, offset) {
+        return Ok(Json(page));
+    }
+
+    let leagues = crate::db::league::get_recent_public_leagues(&state.db, limit, offset).await?;
+    let items = leagues
+        .into_iter()
+        .map(|league| FeedItem::LeagueCreated {
+            scoring_type: league.scoring_type(),
+            league_id: league.id,
+            league_name: league.name,
+            max_teams: league.max_teams,
+            draft_time: league.draft_time,
+            created_at: league.created_at,
+        })
+        .collect();
+
+    let page = FeedPage { items, limit, offset };
+    state.feed_cache.insert(page.clone());
+    Ok(Json(page))
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::http::Status;
+    use sqlx::PgPool;
+    use crate::models::feed::{FeedItem, FeedPage};
+    use crate::test_support;
+
+    #[sqlx::test]
+    async fn private_leagues_never_appear_in_the_feed(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let public = test_support::league(&pool, admin.id, true).await;
+        let private = test_support::league(&pool, admin.id, false).await;
+        let client = test_support::client(pool).await;
+
+        let response = client.get("/feed").dispatch().await;
+        assert_eq!(response.status(), Status::Ok);
+        let page = response.into_json::<FeedPage>().await.unwrap();
+        let league_ids: Vec<i64> = page
+            .items
+            .iter()
+            .map(|item| match item {
+                FeedItem::LeagueCreated { league_id, .. } => *league_id,
+            })
+            .collect();
+        assert_eq!(league_ids, vec![public.id]);
+        assert!(!league_ids.contains(&private.id));
+    }
+}
\ No newline at end of file
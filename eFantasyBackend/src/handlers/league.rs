@@ -1,9 +1,12 @@
 use rocket::State;
 use rocket::serde::json::Json;
+use rocket::http::ContentType;
+use chrono::Utc;
 use crate::AppState;
-use crate::models::league::{League, NewLeague};
+use crate::models::league::{LeagueView, NewLeague, JoinLeagueRequest, UpcomingDraft, UpdateLeaguePartial, PaginatedLeagues, ScoringType};
 use crate::errors::LeagueError;
 use crate::guards::AuthGuard;
+use crate::season::{self, SeasonWeek};
 
 /// Handler for creating a new league
 ///
@@ -15,11 +18,389 @@ use crate::guards::AuthGuard;
 ///
 /// # Returns
 ///
-/// Returns the created League as JSON on success, or a LeagueError on failure
+/// Returns the created league as a LeagueView on success, or a LeagueError on failure
 #[post("/leagues", data = "<new_league>")]
-pub async fn create_league(state: &State<AppState>, new_league: Json<NewLeague>, auth: AuthGuard) -> Result<Json<League>, LeagueError> {
+pub async fn create_league(state: &State<AppState>, new_league: Json<NewLeague>, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    if state.word_filter.contains_banned_word(&new_league.name) {
+        return Err(LeagueError::ValidationError("League name contains a banned word".to_string()));
+    }
     // Call the database function to create the league
     let league = crate::db::league::create_league(&state.db, new_league.into_inner(), auth.user_id).await?;
     // Return the created league as JSON
-    Ok(Json(league))
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for joining a league
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league to join
+/// * `join_request` - Optional body carrying the join password for private leagues
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the updated league as a LeagueView on success, or a LeagueError on failure
+#[post("/leagues/<id>/join", data = "<join_request>")]
+pub async fn join_league(state: &State<AppState>, id: i64, join_request: Option<Json<JoinLeagueRequest>>, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    let password = join_request.and_then(|request| request.into_inner().password);
+    let league = crate::db::league::join_league(&state.db, id, auth.user_id, password.as_deref()).await?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for listing the authenticated user's upcoming drafts
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the user's leagues whose draft is still in the future, soonest first
+#[get("/me/drafts/upcoming")]
+pub async fn get_upcoming_drafts(state: &State<AppState>, auth: AuthGuard) -> Result<Json<Vec<UpcomingDraft>>, LeagueError> {
+    let now = Utc::now();
+    let leagues = crate::db::league::get_user_leagues(&state.db, auth.user_id).await?;
+
+    // Leagues come back ordered by draft_time, so the result is already soonest first
+    let upcoming = leagues
+        .into_iter()
+        .filter(|league| !league.draft_started())
+        .map(|league| UpcomingDraft {
+            seconds_until_draft: (league.draft_time - now).num_seconds(),
+            league: LeagueView::new(league, auth.user_id),
+        })
+        .collect();
+
+    Ok(Json(upcoming))
+}
+
+/// Handler for getting a league's current week
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the league's season phase and, during the regular season, the week number
+#[get("/leagues/<id>/current-week")]
+pub async fn get_current_week(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<Json<SeasonWeek>, LeagueError> {
+    let league = crate::db::league::get_league_by_id(&state.db, id).await?;
+    league.assert_visible_to(auth.user_id)?;
+    Ok(Json(season::league_week(&league)))
+}
+
+/// Handler for downloading a league's calendar as an iCalendar feed
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns a `text/calendar` feed with the draft and each regular-season week
+#[get("/leagues/<id>/calendar.ics")]
+pub async fn get_league_calendar(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<(ContentType, String), LeagueError> {
+    let league = crate::db::league::get_league_by_id(&state.db, id).await?;
+    league.assert_visible_to(auth.user_id)?;
+    Ok((ContentType::Calendar, crate::calendar::league_calendar(&league)))
+}
+
+/// Handler for locking a league during admin maintenance
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `auth` - The authenticated user information, who must be the league admin
+///
+/// # Returns
+///
+/// Returns the locked league as a LeagueView on success, or a LeagueError on failure
+#[post("/leagues/<id>/lock")]
+pub async fn lock_league(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    let league = crate::db::league::set_league_locked(&state.db, id, auth.user_id, true).await?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for unlocking a league after admin maintenance
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `auth` - The authenticated user information, who must be the league admin
+///
+/// # Returns
+///
+/// Returns the unlocked league as a LeagueView on success, or a LeagueError on failure
+#[post("/leagues/<id>/unlock")]
+pub async fn unlock_league(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    let league = crate::db::league::set_league_locked(&state.db, id, auth.user_id, false).await?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for partially updating a league's settings
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `update` - The settings to change; omitted fields are left as they are
+/// * `auth` - The authenticated user information, who must be the league admin
+///
+/// # Returns
+///
+/// Returns the updated league as a LeagueView on success, or a LeagueError on failure
+#[patch("/leagues/<id>", data = "<update>")]
+pub async fn patch_league(state: &State<AppState>, id: i64, update: Json<UpdateLeaguePartial>, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    if let Some(name) = update.name.as_deref() {
+        if state.word_filter.contains_banned_word(name) {
+            return Err(LeagueError::ValidationError("League name contains a banned word".to_string()));
+        }
+    }
+    let league = crate::db::league::update_league_partial(&state.db, id, auth.user_id, update.into_inner()).await?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for finding public leagues the authenticated user could join
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `limit` - The maximum number of leagues to return (defaults to 20, capped at 100)
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns joinable leagues ranked by fill level, then soonest draft
+#[get("/leagues/discover?<limit>")]
+pub async fn discover_leagues(state: &State<AppState>, limit: Option<i64>, auth: AuthGuard) -> Result<Json<Vec<LeagueView>>, LeagueError> {
+    let limit = limit.unwrap_or(20).clamp(1, 100);
+    let leagues = crate::db::league::discover_leagues(&state.db, auth.user_id, limit).await?;
+    Ok(Json(leagues.into_iter().map(|league| LeagueView::new(league, auth.user_id)).collect()))
+}
+
+/// Handler for listing public leagues a page at a time
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `limit` - The page size (defaults to 20, capped at 100)
+/// * `offset` - The number of leagues to skip
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns a page of public leagues, newest first, with the total count
+#[get("/leagues/public?<limit>&<offset>")]
+pub async fn get_public_leagues(state: &State<AppState>, limit: Option<i64>, offset: Option<i64>, auth: AuthGuard) -> Result<Json<PaginatedLeagues>, LeagueError> {
+    let limit = limit.unwrap_or(20).clamp(1, 100);
+    let offset = offset.unwrap_or(0).max(0);
+
+    let leagues = crate::db::league::get_recent_public_leagues(&state.db, limit, offset).await?;
+    let total = crate::db::league::count_public_leagues(&state.db).await?;
+    Ok(Json(PaginatedLeagues {
+        items: leagues.into_iter().map(|league| LeagueView::new(league, auth.user_id)).collect(),
+        total,
+        limit,
+        offset,
+    }))
+}
+
+/// Handler for fetching a single league
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the league as a LeagueView, or LeagueError::NotFound if it doesn't
+/// exist or is a private league the user isn't part of. Non-members get 404
+/// rather than NotAuthorized so private leagues can't be discovered by id.
+#[get("/leagues/<id>")]
+pub async fn get_league(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    let league = crate::db::league::get_league_by_id(&state.db, id).await?;
+    league.assert_visible_to(auth.user_id)?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for removing a participant from a league (admin only, before the draft)
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `user_id` - The ID of the participant to remove
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the updated league as a LeagueView on success, or a LeagueError on failure
+#[post("/leagues/<id>/kick/<user_id>")]
+pub async fn kick_participant(state: &State<AppState>, id: i64, user_id: i64, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    let league = crate::db::league::kick_participant(&state.db, id, auth.user_id, user_id).await?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for handing the admin role to another participant
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `new_admin_id` - The ID of the participant who becomes admin
+/// * `auth` - The authenticated user information, who must be the current admin
+///
+/// # Returns
+///
+/// Returns the updated league as a LeagueView on success, or a LeagueError on failure
+#[post("/leagues/<id>/transfer-admin/<new_admin_id>")]
+pub async fn transfer_admin(state: &State<AppState>, id: i64, new_admin_id: i64, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    let league = crate::db::league::transfer_admin(&state.db, id, auth.user_id, new_admin_id).await?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for searching public leagues by name
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `q` - The text to look for in league names
+/// * `scoring_type` - Only return leagues with this scoring type, if given
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns up to 50 matching public leagues, prefix matches first
+#[get("/leagues/search?<q>&<scoring_type>")]
+pub async fn search_leagues(state: &State<AppState>, q: &str, scoring_type: Option<&str>, auth: AuthGuard) -> Result<Json<Vec<LeagueView>>, LeagueError> {
+    let q = q.trim();
+    if q.is_empty() {
+        return Err(LeagueError::ValidationError("Search query must not be empty".to_string()));
+    }
+
+    let scoring_type = scoring_type.map(ScoringType::parse).transpose()?;
+
+    let leagues = crate::db::league::search_public_leagues(&state.db, q, scoring_type.map(|s| s.as_str()), 50).await?;
+    Ok(Json(leagues.into_iter().map(|league| LeagueView::new(league, auth.user_id)).collect()))
+}
+
+/// Handler for generating a random draft order (admin only, before the draft)
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the updated league, including its draft order, as a LeagueView
+#[post("/leagues/<id>/draft-order")]
+pub async fn generate_draft_order(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    // Always shuffle from entropy so an admin can't pick a seed that favours them
+    let league = crate::db::league::generate_draft_order(&state.db, id, auth.user_id, None).await?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+/// Handler for starting a league's draft (admin only, requires a draft order)
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the updated league, now in the drafting state, as a LeagueView
+#[post("/leagues/<id>/start-draft")]
+pub async fn start_draft(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<Json<LeagueView>, LeagueError> {
+    let league = crate::db::league::start_draft(&state.db, id, auth.user_id).await?;
+    Ok(Json(LeagueView::new(league, auth.user_id)))
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::http::Status;
+    use sqlx::PgPool;
+    use crate::models::league::PaginatedLeagues;
+    use crate::test_support;
+
+    #[sqlx::test]
+    async fn public_league_is_visible_to_anyone(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        test_support::user(&pool, "outsider").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        let client = test_support::client(pool).await;
+        let token = test_support::login(&client, "outsider").await;
+
+        let response = client.get(format!("/leagues/{}", league.id)).header(test_support::bearer(&token)).dispatch().await;
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    #[sqlx::test]
+    async fn private_league_is_visible_to_participants_only(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        test_support::user(&pool, "outsider").await;
+        let league = test_support::league(&pool, admin.id, false).await;
+        let client = test_support::client(pool).await;
+
+        let token = test_support::login(&client, "admin").await;
+        let response = client.get(format!("/leagues/{}", league.id)).header(test_support::bearer(&token)).dispatch().await;
+        assert_eq!(response.status(), Status::Ok);
+
+        let token = test_support::login(&client, "outsider").await;
+        let response = client.get(format!("/leagues/{}", league.id)).header(test_support::bearer(&token)).dispatch().await;
+        assert_eq!(response.status(), Status::NotFound);
+    }
+
+
+    #[sqlx::test]
+    async fn public_leagues_page_caps_the_limit_and_applies_the_offset(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let mut public_ids = Vec::new();
+        for _ in 0..3 {
+            public_ids.push(test_support::league(&pool, admin.id, true).await.id);
+        }
+        test_support::league(&pool, admin.id, false).await;
+        public_ids.reverse();
+        let client = test_support::client(pool).await;
+        let token = test_support::login(&client, "admin").await;
+
+        let fetch = |query: &'static str| {
+            let client = &client;
+            let token = &token;
+            async move {
+                let response = client.get(format!("/leagues/public{}", query)).header(test_support::bearer(token)).dispatch().await;
+                assert_eq!(response.status(), Status::Ok);
+                let page = response.into_json::<PaginatedLeagues>().await.unwrap();
+                let ids: Vec<i64> = page.items.iter().map(|league| league.id).collect();
+                (page, ids)
+            }
+        };
+
+        let (page, ids) = fetch("?limit=500").await;
+        assert_eq!((page.limit, page.offset, page.total), (100, 0, 3));
+        assert_eq!(ids, public_ids);
+
+        let (page, ids) = fetch("?limit=2&offset=2").await;
+        assert_eq!((page.limit, page.offset, page.total), (2, 2, 3));
+        assert_eq!(ids, public_ids[2..]);
+
+        let (page, ids) = fetch("?limit=0&offset=-5").await;
+        assert_eq!((page.limit, page.offset), (1, 0));
+        assert_eq!(ids, public_ids[..1]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,134 @@
+use rocket::State;
+use rocket::serde::json::Json;
+use crate::AppState;
+use crate::models::message::{LeagueMessage, NewLeagueMessage};
+use crate::errors::LeagueError;
+use crate::guards::AuthGuard;
+
+/// Maximum length of a message body, in characters
+const MAX_MESSAGE_LENGTH: usize = 1000;
+/// Default number of messages per page
+const DEFAULT_MESSAGE_LIMIT: i64 = 50;
+/// Maximum number of messages per page
+const MAX_MESSAGE_LIMIT: i64 = 100;
+
+/// Handler for posting a message to a league's message board
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `message` - The message to post
+/// * `auth` - The authenticated user information, who must be a participant
+///
+/// # Returns
+///
+/// Returns the created message as JSON on success, or a LeagueError on failure
+#[post("/leagues/<id>/messages", data = "<message>")]
+pub async fn post_league_message(state: &State<AppState>, id: i64, message: Json<NewLeagueMessage>, auth: AuthGuard) -> Result<Json<LeagueMessage>, LeagueError> {
+    let league = crate::db::league::get_league_by_id(&state.db, id).await?;
+    league.assert_member(auth.user_id)?;
+    league.assert_can_modify(auth.user_id)?;
+
+    let body = message.body.trim();
+    if body.is_empty() || body.chars().count() > MAX_MESSAGE_LENGTH {
+        return Err(LeagueError::ValidationError(format!(
+            "Message must be between 1 and {} characters",
+            MAX_MESSAGE_LENGTH
+        )));
+    }
+    if state.word_filter.contains_banned_word(body) {
+        return Err(LeagueError::ValidationError("Message contains a banned word".to_string()));
+    }
+
+    let message = crate::db::message::post_message(&state.db, id, auth.user_id, body).await?;
+    Ok(Json(message))
+}
+
+/// Handler for reading a league's message board
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `before` - Only return messages older than this message ID
+/// * `limit` - The page size (defaults to 50, capped at 100)
+/// * `auth` - The authenticated user information, who must be a participant
+///
+/// # Returns
+///
+/// Returns a page of messages, newest first
+#[get("/leagues/<id>/messages?<before>&<limit>")]
+pub async fn get_league_messages(state: &State<AppState>, id: i64, before: Option<i64>, limit: Option<i64>, auth: AuthGuard) -> Result<Json<Vec<LeagueMessage>>, LeagueError> {
+    let league = crate::db::league::get_league_by_id(&state.db, id).await?;
+    league.assert_member(auth.user_id)?;
+
+    let limit = limit.unwrap_or(DEFAULT_MESSAGE_LIMIT).clamp(1, MAX_MESSAGE_LIMIT);
+    let messages = crate::db::message::get_messages(&state.db, id, before, limit).await?;
+    Ok(Json(messages))
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::http::Status;
+    use sqlx::PgPool;
+    use crate::models::message::LeagueMessage;
+    use crate::test_support;
+
+    #[sqlx::test]
+    async fn non_member_cannot_post(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        test_support::user(&pool, "outsider").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        let client = test_support::client(pool).await;
+        let token = test_support::login(&client, "outsider").await;
+
+        let response = client
+            .post(format!("/leagues/{}/messages", league.id))
+            .header(test_support::bearer(&token))
+            .json(&serde_json::json!({ "body": "gl hf" }))
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::Forbidden);
+    }
+
+    #[sqlx::test]
+    async fn members_read_messages_newest_first_a_page_at_a_time(pool: PgPool) {
+        let admin = test_support::user(&pool, "admin").await;
+        let league = test_support::league(&pool, admin.id, true).await;
+        let client = test_support::client(pool).await;
+        let token = test_support::login(&client, "admin").await;
+
+        for body in ["first", "second", "third"] {
+            let response = client
+                .post(format!("/leagues/{}/messages", league.id))
+                .header(test_support::bearer(&token))
+                .json(&serde_json::json!({ "body": body }))
+                .dispatch()
+                .await;
+            assert_eq!(response.status(), Status::Ok);
+        }
+
+        let read_page = |query: String| {
+            let client = &client;
+            let token = &token;
+            async move {
+                let response = client
+                    .get(format!("/leagues/{}/messages{}", league.id, query))
+                    .header(test_support::bearer(token))
+                    .dispatch()
+                    .await;
+                assert_eq!(response.status(), Status::Ok);
+                response.into_json::<Vec<LeagueMessage>>().await.unwrap()
+            }
+        };
+
+        let page = read_page("?limit=2".to_string()).await;
+        let bodies: Vec<&str> = page.iter().map(|message| message.body.as_str()).collect();
+        assert_eq!(bodies, ["third", "second"]);
+
+        let page = read_page(format!("?limit=2&before={}", page[1].id)).await;
+        let bodies: Vec<&str> = page.iter().map(|message| message.body.as_str()).collect();
+        assert_eq!(bodies, ["first"]);
+    }
+}
\ No newline at end of file
@@ -1,6 +1,6 @@
 use rocket::State;
 use crate::AppState;
-use crate::models::pro::ProPlayer;
+use crate::models::pro::{ProPlayerView, ProPlayerPage, PlayerId, RANKABLE_STATS};
 use rocket::serde::json::Json;
 use rocket::http::Status;
 
@@ -17,19 +17,147 @@ use rocket::http::Status;
 ///
 /// # Returns
 ///
-/// * `Ok(Json<ProPlayer>)` if the player is found, with a 200 OK status
+/// * `Ok(Json<ProPlayerView>)` if the player is found, with a 200 OK status
 /// * `Err(Status)` with an appropriate error status if the player is not found or another error occurs
 #[get("/pro/<id>")]
-pub async fn get_pro_player(state: &State<AppState>, id: &str) -> Result<Json<ProPlayer>, Status> {
-    match crate::db::pro::get_pro_player_by_id(&state.mongo_db, id).await {
-        Ok(pro) => Ok(Json(pro)),
+pub async fn get_pro_player(state: &State<AppState>, id: &str) -> Result<Json<ProPlayerView>, Status> {
+    // Reject malformed ids before touching the database
+    let id = PlayerId::parse(id).map_err(|_| Status::BadRequest)?;
+
+    match crate::db::pro::get_pro_player_by_id(&state.mongo_db, &id).await {
+        Ok(pro) => Ok(Json(ProPlayerView::new(pro))),
         Err(e) => {
             eprintln!("Error in get_pro_player: {}", e);  // Log the error
             match e.as_str() {
-                "Invalid ObjectId format" => Err(Status::BadRequest),
                 "Pro player not found" => Err(Status::NotFound),
                 _ => Err(Status::InternalServerError),
             }
         },
     }
+}
+
+/// Handles GET requests to search pro players by name.
+///
+/// This is the endpoint handler for `/pro/search?name=<name>`. A player matches if the
+/// name equals their current name or any of their previous aliases.
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<ProPlayerView>>)` with the matching players (possibly empty)
+/// * `Err(Status::InternalServerError)` if the lookup fails
+#[get("/pro/search?<name>")]
+pub async fn search_pro_players(state: &State<AppState>, name: &str) -> Result<Json<Vec<ProPlayerView>>, Status> {
+    match crate::db::pro::find_pro_players_by_name(&state.mongo_db, name.trim()).await {
+        Ok(players) => Ok(Json(players.into_iter().map(ProPlayerView::new).collect())),
+        Err(e) => {
+            eprintln!("Error in search_pro_players: {}", e);
+            Err(Status::InternalServerError)
+        },
+    }
+}
+
+/// Handles GET requests for the top pro players at a position, ranked by a stat.
+///
+/// This is the endpoint handler for `/pro/top?position=MID&stat=kda&limit=10`.
+/// The stat must be one of `RANKABLE_STATS`; `limit` defaults to 10 and is capped at 100.
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<ProPlayerView>>)` with the players sorted best first
+/// * `Err(Status::BadRequest)` if the stat name isn't rankable
+/// * `Err(Status::InternalServerError)` if the lookup fails
+#[get("/pro/top?<position>&<stat>&<limit>")]
+pub async fn get_top_pro_players(state: &State<AppState>, position: &str, stat: &str, limit: Option<usize>) -> Result<Json<Vec<ProPlayerView>>, Status> {
+    if !RANKABLE_STATS.contains(&stat) {
+        return Err(Status::BadRequest);
+    }
+    let limit = limit.unwrap_or(10).clamp(1, 100);
+
+    match crate::db::pro::get_top_players(&state.mongo_db, &position.to_uppercase(), stat, limit).await {
+        Ok(players) => Ok(Json(players.into_iter().map(ProPlayerView::new).collect())),
+        Err(e) => {
+            eprintln!("Error in get_top_pro_players: {}", e);
+            Err(Status::InternalServerError)
+        },
+    }
+}
+
+/// Handles GET requests to browse every pro player a page at a time.
+///
+/// This is the endpoint handler for `/pro?limit=20&offset=0&sort_by=kda&order=desc`.
+/// `sort_by` must be "name" or one of `RANKABLE_STATS`, and `order` must be "asc" or
+/// "desc" (defaults to "asc"). `limit` defaults to 20 and is capped at 100.
+///
+/// # Returns
+///
+/// * `Ok(Json<ProPlayerPage>)` with the page of players and the total count
+/// * `Err(Status::BadRequest)` if `sort_by` or `order` isn't recognized, or `offset` is
+///   too large for the database to skip
+/// * `Err(Status::InternalServerError)` if the lookup fails
+#[get("/pro?<limit>&<offset>&<sort_by>&<order>")]
+pub async fn list_pro_players(state: &State<AppState>, limit: Option<usize>, offset: Option<usize>, sort_by: Option<&str>, order: Option<&str>) -> Result<Json<ProPlayerPage>, Status> {
+    let (limit, offset, descending) = parse_pro_page(limit, offset, sort_by, order)?;
+
+    match crate::db::pro::list_pro_players(&state.mongo_db, limit, offset, sort_by, descending).await {
+        Ok((players, total)) => Ok(Json(ProPlayerPage {
+            items: players.into_iter().map(ProPlayerView::new).collect(),
+            total,
+            limit,
+            offset,
+        })),
+        Err(e) => {
+            eprintln!("Error in list_pro_players: {}", e);
+            Err(Status::InternalServerError)
+        },
+    }
+}
+
+/// Validates the paging and sorting parameters of `list_pro_players`, returning the
+/// page size, offset and whether to sort descending
+fn parse_pro_page(limit: Option<usize>, offset: Option<usize>, sort_by: Option<&str>, order: Option<&str>) -> Result<(usize, usize, bool), Status> {
+    if let Some(sort_by) = sort_by {
+        if sort_by != "name" && !RANKABLE_STATS.contains(&sort_by) {
+            return Err(Status::BadRequest);
+        }
+    }
+    let descending = match order.map(|order| order.to_lowercase()).as_deref() {
+        None | Some("asc") => false,
+        Some("desc") => true,
+        Some(_) => return Err(Status::BadRequest),
+    };
+    let limit = limit.unwrap_or(20).clamp(1, 100);
+    // The aggregation pipeline skips by a signed 64-bit count, so larger offsets would wrap
+    let offset = offset.unwrap_or(0);
+    if offset > i64::MAX as usize {
+        return Err(Status::BadRequest);
+    }
+    Ok((limit, offset, descending))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pro_page_defaults_and_clamps_the_limit() {
+        assert_eq!(parse_pro_page(None, None, None, None), Ok((20, 0, false)));
+        assert_eq!(parse_pro_page(Some(0), Some(40), None, None), Ok((1, 40, false)));
+        assert_eq!(parse_pro_page(Some(500), None, None, Some("DESC")), Ok((100, 0, true)));
+    }
+
+    #[test]
+    fn pro_page_rejects_offsets_that_would_wrap() {
+        let largest = i64::MAX as usize;
+        assert_eq!(parse_pro_page(None, Some(largest), None, None), Ok((20, largest, false)));
+        assert_eq!(parse_pro_page(None, Some(largest + 1), None, None), Err(Status::BadRequest));
+        assert_eq!(parse_pro_page(None, Some(usize::MAX), None, None), Err(Status::BadRequest));
+    }
+
+    #[test]
+    fn pro_page_rejects_unknown_sort_fields_and_orders() {
+        assert_eq!(parse_pro_page(None, None, Some("name"), None), Ok((20, 0, false)));
+        assert_eq!(parse_pro_page(None, None, Some("kda"), None), Ok((20, 0, false)));
+        assert_eq!(parse_pro_page(None, None, Some("height"), None), Err(Status::BadRequest));
+        assert_eq!(parse_pro_page(None, None, None, Some("sideways")), Err(Status::BadRequest));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,115 @@
+use rocket::State;
+use rocket::serde::json::Json;
+use crate::AppState;
+use crate::models::team::{Team, NewTeam};
+use crate::models::pro::PlayerId;
+use crate::errors::TeamError;
+use crate::guards::AuthGuard;
+
+/// Handler for creating the authenticated user's team in a league
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `new_team` - The data for the new team, provided in the request body
+/// * `auth` - The authenticated user information, who must be a participant
+///
+/// # Returns
+///
+/// Returns the created team as JSON on success, or a TeamError on failure
+#[post("/leagues/<id>/teams", data = "<new_team>")]
+pub async fn create_team(state: &State<AppState>, id: i64, new_team: Json<NewTeam>, auth: AuthGuard) -> Result<Json<Team>, TeamError> {
+    if new_team.name.trim().is_empty() {
+        return Err(TeamError::ValidationError("Team name must not be empty".to_string()));
+    }
+    if state.word_filter.contains_banned_word(&new_team.name) {
+        return Err(TeamError::ValidationError("Team name contains a banned word".to_string()));
+    }
+
+    let team = crate::db::team::create_team(&state.db, id, auth.user_id, new_team.into_inner()).await?;
+    Ok(Json(team))
+}
+
+/// Handler for listing the teams in a league
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the league
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the league's teams, or a TeamError on failure
+#[get("/leagues/<id>/teams")]
+pub async fn get_league_teams(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<Json<Vec<Team>>, TeamError> {
+    let league = crate::db::league::get_league_by_id(&state.db, id).await?;
+    league.assert_visible_to(auth.user_id)?;
+
+    let teams = crate::db::team::get_teams_by_league(&state.db, id).await?;
+    Ok(Json(teams))
+}
+
+/// Handler for retrieving a single team
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the team
+/// * `auth` - The authenticated user information
+///
+/// # Returns
+///
+/// Returns the team as JSON, or a TeamError on failure
+#[get("/teams/<id>")]
+pub async fn get_team(state: &State<AppState>, id: i64, auth: AuthGuard) -> Result<Json<Team>, TeamError> {
+    let team = crate::db::team::get_team(&state.db, id).await?;
+    let league = crate::db::league::get_league_by_id(&state.db, team.league_id).await?;
+    league.assert_visible_to(auth.user_id)?;
+
+    Ok(Json(team))
+}
+
+/// Handler for adding a pro player to a team's roster
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the team
+/// * `player_id` - The ID of the pro player to add
+/// * `auth` - The authenticated user information, who must own the team
+///
+/// # Returns
+///
+/// Returns the updated team as JSON on success, or a TeamError on failure
+#[post("/teams/<id>/roster/<player_id>")]
+pub async fn add_roster_player(state: &State<AppState>, id: i64, player_id: &str, auth: AuthGuard) -> Result<Json<Team>, TeamError> {
+    let player_id = parse_player_id(player_id)?;
+    let team = crate::db::team::add_player_to_roster(&state.db, &state.mongo_db, id, auth.user_id, &player_id).await?;
+    Ok(Json(team))
+}
+
+/// Handler for dropping a pro player from a team's roster
+///
+/// # Arguments
+///
+/// * `state` - The shared application state
+/// * `id` - The ID of the team
+/// * `player_id` - The ID of the pro player to drop
+/// * `auth` - The authenticated user information, who must own the team
+///
+/// # Returns
+///
+/// Returns the updated team as JSON on success, or a TeamError on failure
+#[delete("/teams/<id>/roster/<player_id>")]
+pub async fn drop_roster_player(state: &State<AppState>, id: i64, player_id: &str, auth: AuthGuard) -> Result<Json<Team>, TeamError> {
+    let player_id = parse_player_id(player_id)?;
+    let team = crate::db::team::remove_player_from_roster(&state.db, id, auth.user_id, &player_id).await?;
+    Ok(Json(team))
+}
+
+/// Rejects malformed pro player ids before touching either database
+fn parse_player_id(player_id: &str) -> Result<PlayerId, TeamError> {
+    PlayerId::parse(player_id).map_err(|_| TeamError::ValidationError("Invalid pro player id".to_string()))
+}
\ No newline at end of file
@@ -1,11 +1,12 @@
 use rocket::State;
 use crate::AppState;
-use crate::models::user::{NewUser, User, LoginCredentials, UserProfileUpdate, ProfileCompletion, UserStats};
+use crate::models::user::{NewUser, User, LoginCredentials, UserProfileUpdate, ProfileCompletion, UserStats, TokenValidation, AccountDeletion, PasswordChange};
 use crate::errors::UserError;
 use rocket::serde::json::Json;
 use rocket::http::Status;
-use crate::auth::{verify_password, generate_token};
-use crate::guards::{NoAuthGuard, AuthGuard};
+use crate::auth::{verify_password, generate_token, refresh_token, hash_password, needs_rehash};
+use crate::guards::{NoAuthGuard, AuthGuard, RefreshGuard};
+use chrono::{DateTime, Utc};
 
 /// Handles user login
 #[post("/login", data = "<credentials>")]
@@ -15,6 +16,15 @@ pub async fn login(_guard: NoAuthGuard, state: &State<AppState>, credentials: Js
         .map_err(|_| Status::Unauthorized)?;
 
     if verify_password(&credentials.password, &user.password) {
+        // Transparently upgrade hashes made with outdated settings; a failure here
+        // shouldn't block the login itself
+        if needs_rehash(&user.password, user.hash_version) {
+            let new_hash = hash_password(&credentials.password);
+            if let Err(e) = crate::db::user::update_user_password(&state.db, user.id, &new_hash).await {
+                eprintln!("Password rehash failed for user {}: {}", user.id, e);
+            }
+        }
+
         match generate_token(user.id) {
             Ok(token) => Ok(Json(token)),
             Err(e) => {
@@ -30,6 +40,9 @@ pub async fn login(_guard: NoAuthGuard, state: &State<AppState>, credentials: Js
 /// Handles user registration
 #[post("/register", data = "<new_user>")]
 pub async fn register(_guard: NoAuthGuard, state: &State<AppState>, new_user: Json<NewUser>) -> Result<Json<User>, UserError> {
+    if state.word_filter.contains_banned_word(&new_user.username) {
+        return Err(UserError::ValidationError("Username contains a banned word".to_string()));
+    }
     let user = crate::db::user::create_user(&state.db, new_user.into_inner()).await?;
     Ok(Json(user))
 }
@@ -38,14 +51,78 @@ pub async fn register(_guard: NoAuthGuard, state: &State<AppState>, new_user: Js
 #[post("/complete-profile", data = "<profile>")]
 pub async fn complete_profile(auth: AuthGuard, state: &State<AppState>, profile: Json<ProfileCompletion>) -> Result<Json<User>, UserError> {
     println!("complete_profile: Handler called for user_id: {}", auth.user_id);
+    check_nickname(state, profile.nickname.as_deref())?;
     let updated_user = crate::db::user::complete_profile(&state.db, auth.user_id, profile.into_inner()).await?;
     println!("complete_profile: Profile updated successfully");
     Ok(Json(updated_user))
 }
-/// Handles user sign out
+/// Handles user sign out by revoking the presented token
 #[post("/signout")]
-pub async fn sign_out(_auth: AuthGuard) -> Status {
-    Status::Ok
+pub async fn sign_out(auth: AuthGuard, state: &State<AppState>) -> Status {
+    if auth.jti.is_empty() {
+        return Status::Ok;
+    }
+    match crate::db::token::revoke_token(&state.db, &auth.jti, auth.expires_at).await {
+        Ok(_) => Status::Ok,
+        Err(e) => {
+            eprintln!("Token revocation failed: {}", e);
+            Status::InternalServerError
+        }
+    }
+}
+
+/// Exchanges a valid or recently expired token for a fresh one
+#[post("/refresh")]
+pub async fn refresh(auth: RefreshGuard, state: &State<AppState>) -> Result<Json<String>, Status> {
+    // The account may have been closed since the token was issued
+    match crate::db::user::get_user_by_id(&state.db, auth.user_id).await {
+        Ok(_) => {}
+        Err(UserError::NotFound) => return Err(Status::Unauthorized),
+        Err(_) => return Err(Status::InternalServerError),
+    }
+
+    // Each token can be exchanged once. Tokens without an id can't be revoked, so
+    // they can't be refreshed either; their owners sign in again.
+    if auth.jti.is_empty() {
+        return Err(Status::Unauthorized);
+    }
+    match crate::db::token::revoke_token(&state.db, &auth.jti, auth.expires_at).await {
+        Ok(true) => {}
+        // A concurrent refresh already used this token
+        Ok(false) => return Err(Status::Unauthorized),
+        Err(e) => {
+            eprintln!("Token revocation failed: {}", e);
+            return Err(Status::InternalServerError);
+        }
+    }
+
+    match refresh_token(auth.user_id) {
+        Ok(token) => Ok(Json(token)),
+        Err(e) => {
+            eprintln!("Token generation error: {}", e);
+            Err(Status::InternalServerError)
+        }
+    }
+}
+
+/// Checks whether the presented token is valid without side effects.
+/// Invalid or expired tokens are rejected with 401 by the guard.
+#[get("/auth/validate")]
+pub async fn validate_auth(auth: AuthGuard) -> Result<Json<TokenValidation>, Status> {
+    let expires_at = DateTime::<Utc>::from_timestamp(auth.expires_at as i64, 0)
+        .ok_or(Status::InternalServerError)?;
+    Ok(Json(TokenValidation {
+        valid: true,
+        user_id: auth.user_id,
+        expires_at,
+    }))
+}
+
+/// Retrieves the authenticated user
+#[get("/me")]
+pub async fn get_me(auth: AuthGuard, state: &State<AppState>) -> Result<Json<User>, UserError> {
+    let user = crate::db::user::get_user_by_id(&state.db, auth.user_id).await?;
+    Ok(Json(user))
 }
 
 /// Retrieves a user by ID or username
@@ -60,14 +137,33 @@ pub async fn get_user(state: &State<AppState>, id_or_name: &str) -> Result<Json<
     result.map(Json)
 }
 
-/// Deletes a user
-#[delete("/user/<id>")]
-pub async fn delete_user(state: &State<AppState>, id: i64) -> Status {
-    match crate::db::user::delete_user(&state.db, id).await {
-        Ok(true) => Status::NoContent,
-        Ok(false) => Status::NotFound,
-        Err(_) => Status::InternalServerError
+/// Changes the authenticated user's password after verifying the current one
+#[post("/user/password", data = "<change>")]
+pub async fn change_password(auth: AuthGuard, state: &State<AppState>, change: Json<PasswordChange>) -> Result<Status, UserError> {
+    let user = crate::db::user::get_user_by_id(&state.db, auth.user_id).await?;
+    if !verify_password(&change.current_password, &user.password) {
+        return Err(UserError::InvalidCredentials);
+    }
+
+    crate::validation::validate_password_strength(&change.new_password)?;
+    let new_hash = hash_password(&change.new_password);
+    crate::db::user::update_user_password(&state.db, auth.user_id, &new_hash).await?;
+    Ok(Status::NoContent)
+}
+
+/// Closes the authenticated user's own account after re-confirming their password
+#[delete("/me", data = "<confirmation>")]
+pub async fn delete_me(auth: AuthGuard, state: &State<AppState>, confirmation: Json<AccountDeletion>) -> Result<Status, UserError> {
+    let user = crate::db::user::get_user_by_id(&state.db, auth.user_id).await?;
+    if !verify_password(&confirmation.password, &user.password) {
+        return Err(UserError::InvalidCredentials);
+    }
+
+    crate::db::user::close_account(&state.db, auth.user_id).await?;
+    if !auth.jti.is_empty() {
+        crate::db::token::revoke_token(&state.db, &auth.jti, auth.expires_at).await?;
     }
+    Ok(Status::NoContent)
 }
 
 /// Retrieves a user's profile
@@ -85,6 +181,10 @@ pub async fn update_user_profile(
     profile_update: Json<UserProfileUpdate>, 
     _auth: AuthGuard
 ) -> Result<Json<User>, UserError> {
+    check_nickname(state, profile_update.nickname.as_deref())?;
+    if let Some(timezone) = profile_update.timezone.as_deref() {
+        crate::validation::validate_timezone(timezone)?;
+    }
     let updated_user = crate::db::user::update_user_profile(&state.db, id, profile_update.into_inner()).await?;
     Ok(Json(updated_user))
 }
@@ -94,4 +194,100 @@ pub async fn update_user_profile(
 pub async fn get_user_stats(state: &State<AppState>, id: i64, _auth: AuthGuard) -> Result<Json<UserStats>, UserError> {
     let stats = crate::db::user::get_user_statistics(&state.db, id).await?;
     Ok(Json(stats))
+}
+
+/// Rejects nicknames containing a banned word
+fn check_nickname(state: &AppState, nickname: Option<&str>) -> Result<(), UserError> {
+    match nickname {
+        Some(nickname) if state.word_filter.contains_banned_word(nickname) => {
+            Err(UserError::ValidationError("Nickname contains a banned word".to_string()))
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rocket::http::Status;
+    use sqlx::PgPool;
+    use crate::test_support;
+
+    #[sqlx::test]
+    async fn signed_out_token_is_rejected(pool: PgPool) {
+        test_support::user(&pool, "sam").await;
+        let client = test_support::client(pool).await;
+        let token = test_support::login(&client, "sam").await;
+
+        let me = client.get("/me").header(test_support::bearer(&token)).dispatch().await;
+        assert_eq!(me.status(), Status::Ok);
+
+        let signout = client.post("/signout").header(test_support::bearer(&token)).dispatch().await;
+        assert_eq!(signout.status(), Status::Ok);
+
+        let me = client.get("/me").header(test_support::bearer(&token)).dispatch().await;
+        assert_eq!(me.status(), Status::Unauthorized);
+    }
+
+
+    #[sqlx::test]
+    async fn token_can_only_be_refreshed_once(pool: PgPool) {
+        test_support::user(&pool, "sam").await;
+        let client = test_support::client(pool).await;
+        let token = test_support::login(&client, "sam").await;
+
+        let first = client.post("/refresh").header(test_support::bearer(&token)).dispatch().await;
+        assert_eq!(first.status(), Status::Ok);
+        let refreshed = first.into_json::<String>().await.unwrap();
+
+        let second = client.post("/refresh").header(test_support::bearer(&token)).dispatch().await;
+        assert_eq!(second.status(), Status::Unauthorized);
+
+        let me = client.get("/me").header(test_support::bearer(&refreshed)).dispatch().await;
+        assert_eq!(me.status(), Status::Ok);
+    }
+
+
+    #[sqlx::test]
+    async fn password_change_requires_the_current_password(pool: PgPool) {
+        test_support::user(&pool, "sam").await;
+        let client = test_support::client(pool).await;
+        let token = test_support::login(&client, "sam").await;
+
+        let response = client
+            .post("/user/password")
+            .header(test_support::bearer(&token))
+            .json(&serde_json::json!({ "current_password": "Not-The-Password-1", "new_password": "Brand-New-Secret-7" }))
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::Unauthorized);
+        test_support::login(&client, "sam").await;
+    }
+
+    #[sqlx::test]
+    async fn changed_password_is_used_for_the_next_login(pool: PgPool) {
+        test_support::user(&pool, "sam").await;
+        let client = test_support::client(pool).await;
+        let token = test_support::login(&client, "sam").await;
+
+        let response = client
+            .post("/user/password")
+            .header(test_support::bearer(&token))
+            .json(&serde_json::json!({ "current_password": test_support::PASSWORD, "new_password": "Brand-New-Secret-7" }))
+            .dispatch()
+            .await;
+        assert_eq!(response.status(), Status::NoContent);
+
+        let old = client
+            .post("/login")
+            .json(&serde_json::json!({ "username": "sam", "password": test_support::PASSWORD }))
+            .dispatch()
+            .await;
+        assert_eq!(old.status(), Status::Unauthorized);
+        let new = client
+            .post("/login")
+            .json(&serde_json::json!({ "username": "sam", "password": "Brand-New-Secret-7" }))
+            .dispatch()
+            .await;
+        assert_eq!(new.status(), Status::Ok);
+    }
 }
\ No newline at end of file
@@ -4,10 +4,16 @@ use sqlx::postgres::PgPool;
 use dotenv::dotenv;
 use reqwest::Client;
 use mongodb::{Client as MongoClient, options::ClientOptions};
+use rocket::serde::json::Json;
 
-use crate::handlers::user::{register, get_user, delete_user, login, sign_out, complete_profile, get_user_profile, update_user_profile, get_user_stats};
-use crate::handlers::pro::{get_pro_player};
-use crate::handlers::league::create_league;
+use crate::validation::WordFilter;
+use crate::errors::{ErrorCatalog, UserError, LeagueError, TeamError};
+use crate::handlers::user::{register, get_user, login, refresh, sign_out, complete_profile, get_user_profile, update_user_profile, get_user_stats, get_me, validate_auth, delete_me, change_password};
+use crate::handlers::pro::{get_pro_player, get_top_pro_players, search_pro_players, list_pro_players};
+use crate::handlers::league::{create_league, join_league, get_upcoming_drafts, get_current_week, get_league_calendar, lock_league, unlock_league, patch_league, discover_leagues, get_public_leagues, get_league, kick_participant, transfer_admin, search_leagues, generate_draft_order, start_draft};
+use crate::handlers::feed::{get_feed, FeedCache};
+use crate::handlers::message::{post_league_message, get_league_messages};
+use crate::handlers::team::{create_team, get_league_teams, get_team, add_roster_player, drop_roster_player};
 
 mod models;
 mod handlers;
@@ -15,6 +21,12 @@ mod db;
 mod errors;
 mod auth;
 mod guards;
+mod validation;
+mod season;
+mod fairings;
+mod calendar;
+#[cfg(test)]
+mod test_support;
 
 
 /// Main application state
@@ -23,6 +35,8 @@ pub struct AppState {
     pub supabase_client: Client,
     pub supabase_api_key: String,
     pub mongo_db: mongodb::Database,
+    pub word_filter: WordFilter,
+    pub feed_cache: FeedCache,
 }
 
 /// Root route handler
@@ -31,6 +45,16 @@ fn index() -> &'static str {
     "Welcome to eFantasy API"
 }
 
+/// Error catalog route handler, listing every API error code with its status and message
+#[get("/errors")]
+fn error_catalog() -> Json<ErrorCatalog> {
+    Json(ErrorCatalog {
+        user_errors: UserError::catalog(),
+        league_errors: LeagueError::catalog(),
+        team_errors: TeamError::catalog(),
+    })
+}
+
 /// Conflict error catcher
 #[catch(409)]
 fn conflict_catcher() -> &'static str {
@@ -44,21 +68,59 @@ async fn rocket() -> _ {
         Err(e) => println!("Failed to load .env file: {:?}", e),
     }
     let state = initialize_app_state().await.expect("Failed to initialize app state");
+    spawn_revocation_cleanup(state.db.clone());
+    build_rocket(state)
+}
+
+/// Builds the application around the given state, with every route and catcher mounted
+fn build_rocket(state: AppState) -> rocket::Rocket<rocket::Build> {
     rocket::build()
         .manage(state)
+        .attach(fairings::DebugTiming::from_env())
         .mount("/", routes![
             index, 
+            error_catalog,
             register, 
             get_user, 
-            delete_user, 
             get_pro_player, 
+            get_top_pro_players,
+            search_pro_players,
+            list_pro_players,
             login, 
+            refresh,
             sign_out,  
             complete_profile,
             get_user_profile,
             update_user_profile,
             get_user_stats,
+            get_me,
+            validate_auth,
+            delete_me,
+            change_password,
             create_league,
+            join_league,
+            get_upcoming_drafts,
+            get_current_week,
+            get_league_calendar,
+            lock_league,
+            unlock_league,
+            patch_league,
+            discover_leagues,
+            get_public_leagues,
+            get_league,
+            kick_participant,
+            transfer_admin,
+            search_leagues,
+            generate_draft_order,
+            start_draft,
+            get_feed,
+            post_league_message,
+            get_league_messages,
+            create_team,
+            get_league_teams,
+            get_team,
+            add_roster_player,
+            drop_roster_player,
         ])
         .register("/", catchers![conflict_catcher])
 }
@@ -70,6 +132,43 @@ async fn connect_to_postgres(url: &str) -> Result<PgPool, sqlx::Error> {
     Ok(pool)
 }
 
+/// Runs pending migrations from the `migrations/` directory
+async fn run_migrations(pool: &PgPool) -> Result<(), sqlx::migrate::MigrateError> {
+    let migrator = sqlx::migrate!();
+    migrator.run(pool).await?;
+    for migration in migrator.iter() {
+        println!("Migration {} ({}) is applied", migration.version, migration.description);
+    }
+    println!("Successfully ran database migrations");
+    Ok(())
+}
+
+/// Whether migrations should run on startup, controlled by AUTO_MIGRATE (defaults to true)
+fn auto_migrate_enabled() -> bool {
+    match std::env::var("AUTO_MIGRATE") {
+        Ok(value) => !matches!(value.trim().to_lowercase().as_str(), "false" | "0" | "no"),
+        Err(_) => true,
+    }
+}
+
+/// How often expired token revocations are purged
+const REVOCATION_CLEANUP_INTERVAL_SECS: u64 = 60 * 60;
+
+/// Periodically deletes revocations for tokens that have expired on their own
+fn spawn_revocation_cleanup(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(REVOCATION_CLEANUP_INTERVAL_SECS));
+        loop {
+            interval.tick().await;
+            match db::token::purge_expired_revocations(&pool).await {
+                Ok(purged) if purged > 0 => println!("Purged {} expired token revocations", purged),
+                Ok(_) => {}
+                Err(e) => eprintln!("Failed to purge expired token revocations: {}", e),
+            }
+        }
+    });
+}
+
 /// Establishes connection to MongoDB
 async fn connect_to_mongodb(uri: &str) -> Result<mongodb::Database, mongodb::error::Error> {
     let db_name = std::env::var("MONGODB_NAME").unwrap();
@@ -95,8 +194,14 @@ async fn initialize_app_state() -> Result<AppState, Box<dyn std::error::Error>>
     let mongodb_uri = std::env::var("MONGODB_URI")?;
 
     let db = connect_to_postgres(&postgres_url).await?;
+    if auto_migrate_enabled() {
+        run_migrations(&db).await?;
+    } else {
+        println!("AUTO_MIGRATE disabled, skipping database migrations");
+    }
     let mongo_db = connect_to_mongodb(&mongodb_uri).await?;
     let supabase_client = create_supabase_client()?;
+    let word_filter = WordFilter::from_env()?;
 
     println!("All connections established successfully");
 
@@ -105,5 +210,7 @@ async fn initialize_app_state() -> Result<AppState, Box<dyn std::error::Error>>
         supabase_client,
         supabase_api_key,
         mongo_db,
+        word_filter,
+        feed_cache: FeedCache::default(),
     })
 }
\ No newline at end of file
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use crate::models::league::ScoringType;
+
+/// A single entry in the public activity feed.
+/// Only ever built from public leagues.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FeedItem {
+    /// A new public league was created
+    LeagueCreated {
+        league_id: i64,
+        league_name: String,
+        scoring_type: ScoringType,
+        max_teams: i32,
+        draft_time: DateTime<Utc>,
+        created_at: DateTime<Utc>,
+    },
+}
+
+/// A page of the public activity feed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeedPage {
+    pub items: Vec<FeedItem>,
+    pub limit: i64,
+    pub offset: i64,
+}
\ No newline at end of file
@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use crate::errors::LeagueError;
 
-/// Represents a league in the fantasy sports system
+/// Represents a league row in the database.
+///
+/// Handlers return `LeagueView` instead so internal fields stay out of API responses.
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
 pub struct League {
     pub id: i64,
@@ -13,6 +16,16 @@ pub struct League {
     pub scoring_type: String,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub participants: Vec<i64>,
+    /// Argon2 hash of the optional join password, never sent to clients
+    #[serde(skip_serializing)]
+    pub join_password: Option<String>,
+    /// While locked, only the admin may change anything in the league
+    pub locked: bool,
+    /// Participants in draft pick order, once the admin has generated it
+    pub draft_order: Option<Vec<i64>>,
+    /// Lifecycle state, one of the `LeagueStatus` names
+    pub status: String,
 }
 
 /// Represents the data required to create a new league
@@ -23,4 +36,300 @@ pub struct NewLeague {
     pub is_public: bool,
     pub draft_time: DateTime<Utc>,
     pub scoring_type: String,
+    /// Optional shared password that lets users join without an invitation
+    pub join_password: Option<String>,
+}
+
+/// Represents a partial update to a league's settings.
+/// Only the fields that are present are changed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct UpdateLeaguePartial {
+    pub name: Option<String>,
+    pub max_teams: Option<i32>,
+    pub is_public: Option<bool>,
+    pub draft_time: Option<DateTime<Utc>>,
+    pub scoring_type: Option<String>,
+    pub join_password: Option<String>,
+    /// Replaces the whole participants list; left untouched when omitted
+    pub participants: Option<Vec<i64>>,
+}
+
+/// The scoring systems a league can use. Stored as text in the `scoring_type` column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoringType {
+    Standard,
+    Ppr,
+    Custom,
+}
+
+impl ScoringType {
+    /// Parses a scoring type, ignoring case and surrounding whitespace
+    pub fn parse(value: &str) -> Result<Self, LeagueError> {
+        match value.trim().to_lowercase().as_str() {
+            "standard" => Ok(ScoringType::Standard),
+            "ppr" => Ok(ScoringType::Ppr),
+            "custom" => Ok(ScoringType::Custom),
+            _ => Err(LeagueError::InvalidScoringType),
+        }
+    }
+
+    /// The canonical text stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoringType::Standard => "standard",
+            ScoringType::Ppr => "ppr",
+            ScoringType::Custom => "custom",
+        }
+    }
+}
+
+/// Represents the body of a request to join a league
+#[derive(Debug, Serialize, Deserialize)]
+pub struct JoinLeagueRequest {
+    pub password: Option<String>,
+}
+
+/// The lifecycle stage of a league. Stored as text in the `status` column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LeagueStatus {
+    /// The draft has not happened yet
+    Pending,
+    /// The admin has started the draft
+    Drafting,
+    /// The season is underway
+    Active,
+    /// The season is over
+    Completed,
+}
+
+impl LeagueStatus {
+    /// Parses a stored status, treating unknown values as `Pending`
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "drafting" => LeagueStatus::Drafting,
+            "active" => LeagueStatus::Active,
+            "completed" => LeagueStatus::Completed,
+            _ => LeagueStatus::Pending,
+        }
+    }
+
+    /// The text stored in the database
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LeagueStatus::Pending => "pending",
+            LeagueStatus::Drafting => "drafting",
+            LeagueStatus::Active => "active",
+            LeagueStatus::Completed => "completed",
+        }
+    }
+}
+
+/// The API representation of a league, with computed fields for the viewer
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LeagueView {
+    pub id: i64,
+    pub name: String,
+    pub admin_id: i64,
+    pub max_teams: i32,
+    pub is_public: bool,
+    pub draft_time: DateTime<Utc>,
+    pub scoring_type: ScoringType,
+    pub participants: Vec<i64>,
+    pub draft_order: Option<Vec<i64>>,
+    pub locked: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub status: LeagueStatus,
+    /// The regular-season week in progress, if any
+    pub current_week: Option<u32>,
+    pub is_admin: bool,
+    pub participant_count: usize,
+    pub is_full: bool,
+    pub has_join_password: bool,
+}
+
+impl League {
+    /// Whether the given user may see this league: public leagues are visible to
+    /// everyone, private ones only to their participants
+    pub fn is_visible_to(&self, user_id: i64) -> bool {
+        self.is_public || self.participants.contains(&user_id)
+    }
+
+    /// Checks that the user may see this league.
+    ///
+    /// Private leagues the user can't see are reported as `NotFound` rather than
+    /// `NotAuthorized`, so callers can't tell a hidden league from a missing one and
+    /// enumerate private league ids. The tradeoff is a less specific error for
+    /// legitimate users who mistype an id or were removed from a league.
+    pub fn assert_visible_to(&self, user_id: i64) -> Result<(), LeagueError> {
+        if self.is_visible_to(user_id) {
+            Ok(())
+        } else {
+            Err(LeagueError::NotFound)
+        }
+    }
+
+    /// Checks that the user is a participant. Non-participants of a private league
+    /// get `NotFound` (see `assert_visible_to`), of a public league `NotAuthorized`.
+    pub fn assert_member(&self, user_id: i64) -> Result<(), LeagueError> {
+        self.assert_visible_to(user_id)?;
+        if !self.participants.contains(&user_id) {
+            return Err(LeagueError::NotAuthorized);
+        }
+        Ok(())
+    }
+
+    /// The league's lifecycle state
+    pub fn status(&self) -> LeagueStatus {
+        LeagueStatus::parse(&self.status)
+    }
+
+    /// The league's scoring system. Unrecognized stored values are treated as custom scoring.
+    pub fn scoring_type(&self) -> ScoringType {
+        ScoringType::parse(&self.scoring_type).unwrap_or(ScoringType::Custom)
+    }
+
+    /// Whether the draft has begun, either because the admin started it or because
+    /// the scheduled draft time has passed. Draft-shaping settings are frozen from then on.
+    pub fn draft_started(&self) -> bool {
+        self.status() != LeagueStatus::Pending || self.draft_time <= Utc::now()
+    }
+
+    /// Checks that the user may modify the league, rejecting members while it is locked
+    pub fn assert_can_modify(&self, user_id: i64) -> Result<(), LeagueError> {
+        if self.locked && self.admin_id != user_id {
+            return Err(LeagueError::LeagueLocked);
+        }
+        Ok(())
+    }
+}
+
+impl LeagueView {
+    /// Builds the view of a league as seen by the given user
+    pub fn new(league: League, viewer_id: i64) -> Self {
+        let status = league.status();
+        let scoring_type = league.scoring_type();
+        let current_week = crate::season::current_week(&league);
+        let participant_count = league.participants.len();
+
+        LeagueView {
+            id: league.id,
+            name: league.name,
+            admin_id: league.admin_id,
+            max_teams: league.max_teams,
+            is_public: league.is_public,
+            draft_time: league.draft_time,
+            scoring_type,
+            draft_order: league.draft_order,
+            locked: league.locked,
+            created_at: league.created_at,
+            updated_at: league.updated_at,
+            status,
+            current_week,
+            is_admin: league.admin_id == viewer_id,
+            participant_count,
+            is_full: participant_count >= league.max_teams.max(0) as usize,
+            has_join_password: league.join_password.is_some(),
+            participants: league.participants,
+        }
+    }
+}
+
+/// A league whose draft is still ahead, with a countdown for the viewer
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UpcomingDraft {
+    pub league: LeagueView,
+    pub seconds_until_draft: i64,
+}
+
+/// A page of leagues with the total number available, for building pagers
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaginatedLeagues {
+    pub items: Vec<LeagueView>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn league(participants: Vec<i64>, max_teams: i32, scoring_type: &str) -> League {
+        let now = Utc::now();
+        League {
+            id: 1,
+            name: "Summer Split".to_string(),
+            admin_id: 1,
+            max_teams,
+            is_public: true,
+            draft_time: now,
+            scoring_type: scoring_type.to_string(),
+            created_at: now,
+            updated_at: now,
+            participants,
+            join_password: None,
+            locked: false,
+            draft_order: None,
+            status: "pending".to_string(),
+        }
+    }
+
+    #[test]
+    fn scoring_type_parse_ignores_case_and_whitespace() {
+        assert_eq!(ScoringType::parse(" PPR ").unwrap(), ScoringType::Ppr);
+        assert_eq!(ScoringType::parse("Standard").unwrap(), ScoringType::Standard);
+        assert_eq!(ScoringType::parse("custom").unwrap(), ScoringType::Custom);
+    }
+
+    #[test]
+    fn scoring_type_parse_rejects_unknown_values() {
+        for value in ["", "half-ppr", "points"] {
+            assert!(matches!(ScoringType::parse(value), Err(LeagueError::InvalidScoringType)));
+        }
+    }
+
+    #[test]
+    fn scoring_type_serializes_as_its_stored_text() {
+        for scoring_type in [ScoringType::Standard, ScoringType::Ppr, ScoringType::Custom] {
+            let json = serde_json::to_string(&scoring_type).unwrap();
+            assert_eq!(json, format!("\"{}\"", scoring_type.as_str()));
+            assert_eq!(ScoringType::parse(scoring_type.as_str()).unwrap(), scoring_type);
+        }
+    }
+
+    #[test]
+    fn unrecognized_stored_scoring_type_reads_as_custom() {
+        assert_eq!(league(vec![1], 10, "legacy").scoring_type(), ScoringType::Custom);
+        assert_eq!(LeagueView::new(league(vec![1], 10, "PPR"), 1).scoring_type, ScoringType::Ppr);
+    }
+
+    #[test]
+    fn league_view_is_full_once_every_slot_is_taken() {
+        assert!(!LeagueView::new(league(vec![1], 2, "standard"), 1).is_full);
+        assert!(LeagueView::new(league(vec![1, 2], 2, "standard"), 1).is_full);
+        // A corrupt negative size never reports free slots
+        assert!(LeagueView::new(league(vec![1], -1, "standard"), 1).is_full);
+    }
+
+
+    #[test]
+    fn private_league_is_not_found_for_non_members() {
+        let private = League { is_public: false, ..league(vec![1, 2], 10, "standard") };
+        assert!(matches!(private.assert_visible_to(3), Err(LeagueError::NotFound)));
+        assert!(private.assert_visible_to(2).is_ok());
+    }
+
+
+    #[test]
+    fn locked_league_can_only_be_modified_by_the_admin() {
+        let locked = League { locked: true, ..league(vec![1, 2], 10, "standard") };
+        assert!(matches!(locked.assert_can_modify(2), Err(LeagueError::LeagueLocked)));
+        assert!(locked.assert_can_modify(1).is_ok());
+
+        let unlocked = league(vec![1, 2], 10, "standard");
+        assert!(unlocked.assert_can_modify(2).is_ok());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+/// Represents a message posted to a league's message board
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LeagueMessage {
+    pub id: i64,
+    pub league_id: i64,
+    pub author_id: i64,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Represents the data required to post a new message
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewLeagueMessage {
+    pub body: String,
+}
\ No newline at end of file
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use mongodb::bson::oid::ObjectId;
+use std::fmt;
 
 /// Represents a professional player in esports with their statistics and attributes.
 /// All fields are optional to accommodate varying data availability across different players.
@@ -12,6 +13,9 @@ pub struct ProPlayer {
     /// The player's in-game name or alias.
     pub name: Option<String>,
 
+    /// Previous in-game names the player has gone by.
+    pub aliases: Option<Vec<String>>,
+
     /// The player's country of origin, typically represented by a country code.
     pub country: Option<String>,
 
@@ -101,4 +105,353 @@ pub struct ProPlayer {
     /// Number of Pentakills (killing all 5 enemy champions) achieved.
     #[serde(rename = "Penta Kills")]
     pub penta_kills: Option<String>,
+}
+
+/// Identifier of a pro player.
+///
+/// Everything stored in Postgres (users, leagues) is keyed by `i64`, but pro players
+/// live in MongoDB and are keyed by `ObjectId`. Anywhere a pro player is referenced
+/// from Postgres (e.g. rosters) the id is stored as the ObjectId's 24-character hex
+/// string. `PlayerId` wraps that string and can only be built from a valid ObjectId,
+/// so malformed ids are rejected at the API boundary instead of deep in a Mongo query.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PlayerId(String);
+
+impl PlayerId {
+    /// Parses and validates a pro player id from its hex representation
+    pub fn parse(id: &str) -> Result<Self, String> {
+        ObjectId::parse_str(id.trim())
+            .map(|object_id| PlayerId(object_id.to_hex()))
+            .map_err(|_| "Invalid ObjectId format".to_string())
+    }
+
+    /// Returns the id as a hex string, as stored in Postgres
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the id as a MongoDB ObjectId for querying
+    pub fn object_id(&self) -> ObjectId {
+        ObjectId::parse_str(&self.0).expect("PlayerId always holds a valid ObjectId")
+    }
+}
+
+impl From<ObjectId> for PlayerId {
+    fn from(object_id: ObjectId) -> Self {
+        PlayerId(object_id.to_hex())
+    }
+}
+
+impl TryFrom<String> for PlayerId {
+    type Error = String;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        PlayerId::parse(&id)
+    }
+}
+
+impl From<PlayerId> for String {
+    fn from(id: PlayerId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for PlayerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The five roster positions; a fantasy roster holds at most one player of each
+pub const ROSTER_POSITIONS: &[&str] = &["TOP", "JUNGLE", "MID", "ADC", "SUPPORT"];
+
+impl ProPlayer {
+    /// The player's position normalized to one of `ROSTER_POSITIONS`, or `None` if it
+    /// is missing or not a known position
+    pub fn roster_position(&self) -> Option<&'static str> {
+        let position = self.position.as_deref()?.trim().to_uppercase();
+        ROSTER_POSITIONS.iter().copied().find(|known| *known == position)
+    }
+}
+
+/// Stat names that pro players can be ranked by
+pub const RANKABLE_STATS: &[&str] = &[
+    "games", "win_rate", "kda", "avg_kills", "avg_deaths", "avg_assists", "csm", "gpm",
+    "kp_percentage", "dmg_percentage", "dpm", "vspm", "avg_wpm", "avg_wcpm", "avg_vwpm",
+    "gd_at_15", "csd_at_15", "xpd_at_15", "fb_percentage", "fb_victim", "penta_kills",
+];
+
+/// Returns the MongoDB field holding a rankable stat, or `None` if the stat is unknown.
+/// Field names follow the scraped column headers, as in `ProPlayer`'s serde renames.
+pub fn stat_field(stat: &str) -> Option<&'static str> {
+    let field = match stat {
+        "games" => "Games",
+        "win_rate" => "Win rate",
+        "kda" => "KDA",
+        "avg_kills" => "Avg kills",
+        "avg_deaths" => "Avg deaths",
+        "avg_assists" => "Avg assists",
+        "csm" => "CSM",
+        "gpm" => "GPM",
+        "kp_percentage" => "KP%",
+        "dmg_percentage" => "DMG%",
+        "dpm" => "DPM",
+        "vspm" => "VSPM",
+        "avg_wpm" => "Avg WPM",
+        "avg_wcpm" => "Avg WCPM",
+        "avg_vwpm" => "Avg VWPM",
+        "gd_at_15" => "GD@15",
+        "csd_at_15" => "CSD@15",
+        "xpd_at_15" => "XPD@15",
+        "fb_percentage" => "FB %",
+        "fb_victim" => "FB Victim",
+        "penta_kills" => "Penta Kills",
+        _ => return None,
+    };
+    Some(field)
+}
+
+/// Numeric view of a pro player's stats. Percentages are stored without the `%`
+/// (62.5 for "62.5%"). Missing stats and "-" placeholders are `None`.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct ProPlayerStats {
+    pub games: Option<f64>,
+    pub win_rate: Option<f64>,
+    pub kda: Option<f64>,
+    pub avg_kills: Option<f64>,
+    pub avg_deaths: Option<f64>,
+    pub avg_assists: Option<f64>,
+    pub csm: Option<f64>,
+    pub gpm: Option<f64>,
+    pub kp_percentage: Option<f64>,
+    pub dmg_percentage: Option<f64>,
+    pub dpm: Option<f64>,
+    pub vspm: Option<f64>,
+    pub avg_wpm: Option<f64>,
+    pub avg_wcpm: Option<f64>,
+    pub avg_vwpm: Option<f64>,
+    pub gd_at_15: Option<f64>,
+    pub csd_at_15: Option<f64>,
+    pub xpd_at_15: Option<f64>,
+    pub fb_percentage: Option<f64>,
+    pub fb_victim: Option<f64>,
+    pub penta_kills: Option<f64>,
+}
+
+/// Returned by `ProPlayer::parsed_stats` when some stats hold text that isn't a number
+#[derive(Debug, thiserror::Error)]
+#[error("Unparseable stats: {}", .fields.join(", "))]
+pub struct StatParseError {
+    /// Names of the stats that couldn't be parsed
+    pub fields: Vec<&'static str>,
+    /// Every stat that did parse; the unparseable ones are `None`
+    pub stats: Box<ProPlayerStats>,
+}
+
+impl ProPlayer {
+    /// Parses every stat into a number, failing if any present stat isn't numeric.
+    /// Missing stats and "-" placeholders aren't errors; they become `None`.
+    pub fn parsed_stats(&self) -> Result<ProPlayerStats, StatParseError> {
+        let mut unparseable = Vec::new();
+        let mut parse = |name: &'static str, raw: &Option<String>| -> Option<f64> {
+            let raw = raw.as_deref()?.trim();
+            if raw.is_empty() || raw == "-" {
+                return None;
+            }
+            let value = parse_stat(raw);
+            if value.is_none() {
+                unparseable.push(name);
+            }
+            value
+        };
+
+        let stats = ProPlayerStats {
+            games: parse("games", &self.games),
+            win_rate: parse("win_rate", &self.win_rate),
+            kda: parse("kda", &self.kda),
+            avg_kills: parse("avg_kills", &self.avg_kills),
+            avg_deaths: parse("avg_deaths", &self.avg_deaths),
+            avg_assists: parse("avg_assists", &self.avg_assists),
+            csm: parse("csm", &self.csm),
+            gpm: parse("gpm", &self.gpm),
+            kp_percentage: parse("kp_percentage", &self.kp_percentage),
+            dmg_percentage: parse("dmg_percentage", &self.dmg_percentage),
+            dpm: parse("dpm", &self.dpm),
+            vspm: parse("vspm", &self.vspm),
+            avg_wpm: parse("avg_wpm", &self.avg_wpm),
+            avg_wcpm: parse("avg_wcpm", &self.avg_wcpm),
+            avg_vwpm: parse("avg_vwpm", &self.avg_vwpm),
+            gd_at_15: parse("gd_at_15", &self.gd_at_15),
+            csd_at_15: parse("csd_at_15", &self.csd_at_15),
+            xpd_at_15: parse("xpd_at_15", &self.xpd_at_15),
+            fb_percentage: parse("fb_percentage", &self.fb_percentage),
+            fb_victim: parse("fb_victim", &self.fb_victim),
+            penta_kills: parse("penta_kills", &self.penta_kills),
+        };
+
+        if unparseable.is_empty() {
+            Ok(stats)
+        } else {
+            Err(StatParseError { fields: unparseable, stats: Box::new(stats) })
+        }
+    }
+}
+
+/// The API representation of a pro player: the raw scraped fields plus their numeric view
+#[derive(Serialize, Debug)]
+pub struct ProPlayerView {
+    #[serde(flatten)]
+    pub player: ProPlayer,
+    pub stats: ProPlayerStats,
+}
+
+impl ProPlayerView {
+    /// Builds the view, keeping whatever stats parse and logging the ones that don't
+    pub fn new(player: ProPlayer) -> Self {
+        let stats = match player.parsed_stats() {
+            Ok(stats) => stats,
+            Err(e) => {
+                eprintln!("Pro player {}: {}", player.id, e);
+                *e.stats
+            }
+        };
+        ProPlayerView { player, stats }
+    }
+}
+
+/// A page of pro players with the total number available, for building pagers
+#[derive(Serialize, Debug)]
+pub struct ProPlayerPage {
+    pub items: Vec<ProPlayerView>,
+    pub total: u64,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+/// Parses a scraped stat string such as "62.5%", "1,024" or "-42" into a number.
+/// Returns `None` for placeholders like "-" or anything else unparseable.
+pub fn parse_stat(raw: &str) -> Option<f64> {
+    let cleaned: String = raw
+        .trim()
+        .trim_end_matches('%')
+        .chars()
+        .filter(|c| *c != ',')
+        .collect();
+    cleaned.trim().parse::<f64>().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "64b7f0c2a1e4d3c2b1a09f8e";
+
+    #[test]
+    fn player_id_accepts_valid_object_ids() {
+        let id = PlayerId::parse(&format!("  {}  ", HEX)).unwrap();
+        assert_eq!(id.as_str(), HEX);
+        assert_eq!(id.object_id().to_hex(), HEX);
+        assert_eq!(PlayerId::from(id.object_id()), id);
+    }
+
+    #[test]
+    fn player_id_normalizes_to_lowercase_hex() {
+        let id = PlayerId::parse(&HEX.to_uppercase()).unwrap();
+        assert_eq!(id.as_str(), HEX);
+    }
+
+    #[test]
+    fn player_id_rejects_malformed_ids() {
+        for id in ["", "42", "not-an-object-id", &HEX[1..], "zzb7f0c2a1e4d3c2b1a09f8e"] {
+            assert!(PlayerId::parse(id).is_err(), "{} should be rejected", id);
+        }
+    }
+
+    #[test]
+    fn player_id_round_trips_through_json_as_a_string() {
+        let id = PlayerId::parse(HEX).unwrap();
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", HEX));
+        assert_eq!(serde_json::from_str::<PlayerId>(&json).unwrap(), id);
+        assert!(serde_json::from_str::<PlayerId>("\"bogus\"").is_err());
+    }
+
+    fn player(win_rate: Option<&str>, kda: Option<&str>, gpm: Option<&str>) -> ProPlayer {
+        ProPlayer {
+            id: ObjectId::new(),
+            name: Some("Faker".to_string()),
+            aliases: None,
+            country: Some("KR".to_string()),
+            position: Some("Mid".to_string()),
+            games: Some("1,024".to_string()),
+            win_rate: win_rate.map(str::to_string),
+            kda: kda.map(str::to_string),
+            avg_kills: None,
+            avg_deaths: None,
+            avg_assists: None,
+            csm: None,
+            gpm: gpm.map(str::to_string),
+            kp_percentage: None,
+            dmg_percentage: None,
+            dpm: None,
+            vspm: None,
+            avg_wpm: None,
+            avg_wcpm: None,
+            avg_vwpm: None,
+            gd_at_15: Some("-42".to_string()),
+            csd_at_15: None,
+            xpd_at_15: None,
+            fb_percentage: None,
+            fb_victim: None,
+            penta_kills: None,
+        }
+    }
+
+    #[test]
+    fn parse_stat_handles_scraped_formats() {
+        assert_eq!(parse_stat("62.5%"), Some(62.5));
+        assert_eq!(parse_stat(" 1,024 "), Some(1024.0));
+        assert_eq!(parse_stat("-42"), Some(-42.0));
+        assert_eq!(parse_stat("-"), None);
+        assert_eq!(parse_stat("n/a"), None);
+    }
+
+    #[test]
+    fn parsed_stats_converts_every_present_stat() {
+        let stats = player(Some("62.5%"), Some("4.1"), Some("-")).parsed_stats().unwrap();
+        assert_eq!(stats.games, Some(1024.0));
+        assert_eq!(stats.win_rate, Some(62.5));
+        assert_eq!(stats.kda, Some(4.1));
+        assert_eq!(stats.gpm, None);
+        assert_eq!(stats.gd_at_15, Some(-42.0));
+        assert_eq!(stats.avg_kills, None);
+    }
+
+    #[test]
+    fn parsed_stats_reports_unparseable_fields_and_keeps_the_rest() {
+        let error = player(Some("62.5%"), Some("Perfect"), None).parsed_stats().unwrap_err();
+        assert_eq!(error.fields, vec!["kda"]);
+        assert_eq!(error.stats.win_rate, Some(62.5));
+        assert_eq!(error.stats.kda, None);
+
+        let view = ProPlayerView::new(player(Some("62.5%"), Some("Perfect"), None));
+        assert_eq!(view.stats.win_rate, Some(62.5));
+    }
+
+    #[test]
+    fn every_rankable_stat_has_a_mongo_field() {
+        let document = mongodb::bson::to_document(&player(None, None, None)).unwrap();
+        for stat in RANKABLE_STATS {
+            let field = stat_field(stat).unwrap_or_else(|| panic!("{} has no field", stat));
+            assert!(document.contains_key(field), "{} isn't a ProPlayer field", field);
+        }
+        assert_eq!(stat_field("win_rate"), Some("Win rate"));
+        assert_eq!(stat_field("name"), None);
+    }
+
+    #[test]
+    fn roster_position_is_case_insensitive() {
+        assert_eq!(player(None, None, None).roster_position(), Some("MID"));
+    }
 }
\ No newline at end of file
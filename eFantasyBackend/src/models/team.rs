@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+
+/// Represents a user's fantasy team in a league
+#[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Team {
+    pub id: i64,
+    pub league_id: i64,
+    pub owner_id: i64,
+    pub name: String,
+    /// Ids of the pro players on the team
+    pub roster: Vec<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Represents the data required to create a new team
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NewTeam {
+    pub name: String,
+}
\ No newline at end of file
@@ -17,6 +17,9 @@ pub struct User {
     pub total_points: f64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    pub timezone: String,
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub hash_version: i32,
 }
 
 /// Represents the data required to create a new user
@@ -41,6 +44,8 @@ pub struct UserProfileUpdate {
     pub nickname: Option<String>,
     pub bio: Option<String>,
     pub avatar_url: Option<String>,
+    /// IANA timezone name, e.g. "America/New_York"
+    pub timezone: Option<String>,
 }
 
 /// Represents the credentials for user login
@@ -59,4 +64,36 @@ pub struct UserStats {
     pub total_points: f64,
     pub leagues_joined: i32, 
     pub teams_created: i32, 
+}
+
+/// A change to apply to a user's statistics, e.g. after a week is finalized
+#[allow(dead_code)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatDelta {
+    pub user_id: i64,
+    pub wins: i32,
+    pub losses: i32,
+    pub ties: i32,
+    pub points: f64,
+}
+
+/// Represents the result of validating an access token
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TokenValidation {
+    pub valid: bool,
+    pub user_id: i64,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Represents the confirmation required to close one's own account
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountDeletion {
+    pub password: String,
+}
+
+/// Represents a request to change one's own password
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PasswordChange {
+    pub current_password: String,
+    pub new_password: String,
 }
\ No newline at end of file
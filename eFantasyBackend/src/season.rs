@@ -0,0 +1,110 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use crate::models::league::League;
+
+/// Length of a fantasy week in days
+const WEEK_LENGTH_DAYS: i64 = 7;
+
+/// Number of regular-season weeks used when SEASON_WEEKS is unset or invalid
+const DEFAULT_SEASON_WEEKS: u32 = 10;
+
+/// Where a league currently is in its season
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "phase", content = "week", rename_all = "snake_case")]
+pub enum SeasonWeek {
+    /// Before the draft; no week has started yet
+    Preseason,
+    /// A regular-season week, numbered from 1
+    RegularSeason(u32),
+    /// Every regular-season week has ended
+    Postseason,
+}
+
+impl SeasonWeek {
+    /// Returns the week number, if the season is underway
+    pub fn number(&self) -> Option<u32> {
+        match self {
+            SeasonWeek::RegularSeason(week) => Some(*week),
+            _ => None,
+        }
+    }
+}
+
+/// Number of regular-season weeks, read from SEASON_WEEKS
+pub fn season_weeks() -> u32 {
+    std::env::var("SEASON_WEEKS")
+        .ok()
+        .and_then(|value| value.trim().parse().ok())
+        .filter(|weeks| *weeks > 0)
+        .unwrap_or(DEFAULT_SEASON_WEEKS)
+}
+
+/// Computes the season week at `now` for a league drafted at `draft_time`.
+///
+/// Week 1 starts at the draft and every week lasts `WEEK_LENGTH_DAYS`.
+pub fn week_at(draft_time: DateTime<Utc>, now: DateTime<Utc>, season_weeks: u32) -> SeasonWeek {
+    if now < draft_time {
+        return SeasonWeek::Preseason;
+    }
+
+    let elapsed_weeks = (now - draft_time).num_days() / WEEK_LENGTH_DAYS;
+    let week = elapsed_weeks + 1;
+    if week > season_weeks as i64 {
+        SeasonWeek::Postseason
+    } else {
+        SeasonWeek::RegularSeason(week as u32)
+    }
+}
+
+/// Computes where a league is in its season right now
+pub fn league_week(league: &League) -> SeasonWeek {
+    week_at(league.draft_time, Utc::now(), season_weeks())
+}
+
+/// Returns the league's current week number, or `None` in the preseason and postseason
+pub fn current_week(league: &League) -> Option<u32> {
+    league_week(league).number()
+}
+
+/// Returns the time a given regular-season week (1-based) starts
+pub fn week_start(draft_time: DateTime<Utc>, week: u32) -> DateTime<Utc> {
+    draft_time + Duration::days(WEEK_LENGTH_DAYS * week.saturating_sub(1) as i64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn draft() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 9, 1, 18, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn preseason_until_the_draft() {
+        assert_eq!(week_at(draft(), draft() - Duration::seconds(1), 10), SeasonWeek::Preseason);
+        assert_eq!(week_at(draft(), draft(), 10), SeasonWeek::RegularSeason(1));
+    }
+
+    #[test]
+    fn week_advances_at_each_week_boundary() {
+        let boundary = week_start(draft(), 2);
+        assert_eq!(boundary, draft() + Duration::days(7));
+        assert_eq!(week_at(draft(), boundary - Duration::seconds(1), 10), SeasonWeek::RegularSeason(1));
+        assert_eq!(week_at(draft(), boundary, 10), SeasonWeek::RegularSeason(2));
+    }
+
+    #[test]
+    fn postseason_after_the_last_week() {
+        let end = week_start(draft(), 11);
+        assert_eq!(week_at(draft(), end - Duration::seconds(1), 10), SeasonWeek::RegularSeason(10));
+        assert_eq!(week_at(draft(), end, 10), SeasonWeek::Postseason);
+    }
+
+    #[test]
+    fn only_regular_season_weeks_have_a_number() {
+        assert_eq!(SeasonWeek::RegularSeason(3).number(), Some(3));
+        assert_eq!(SeasonWeek::Preseason.number(), None);
+        assert_eq!(SeasonWeek::Postseason.number(), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,86 @@
+//! Fixtures shared by the database-backed tests. Each `#[sqlx::test]` gets a fresh
+//! database with every migration applied, so fixtures can insert freely.
+
+use std::sync::Once;
+use chrono::{Duration, Utc};
+use rocket::http::{Header, Status};
+use rocket::local::asynchronous::Client;
+use sqlx::PgPool;
+use crate::AppState;
+use crate::handlers::feed::FeedCache;
+use crate::validation::WordFilter;
+use crate::models::league::{League, NewLeague};
+use crate::models::user::{NewUser, User};
+
+/// Password given to every fixture user; satisfies the strict password policy
+pub const PASSWORD: &str = "Fantasy-League-42";
+
+/// Registers a user named `username` with `PASSWORD`
+pub async fn user(pool: &PgPool, username: &str) -> User {
+    crate::db::user::create_user(pool, NewUser {
+        username: username.to_string(),
+        email: format!("{}@example.com", username),
+        password: PASSWORD.to_string(),
+    })
+    .await
+    .expect("fixture user")
+}
+
+/// Creates a league administered by `admin_id` whose draft is a week away
+pub async fn league(pool: &PgPool, admin_id: i64, is_public: bool) -> League {
+    crate::db::league::create_league(pool, NewLeague {
+        name: "Summer Split".to_string(),
+        max_teams: 4,
+        is_public,
+        draft_time: Utc::now() + Duration::days(7),
+        scoring_type: "standard".to_string(),
+        join_password: None,
+    }, admin_id)
+    .await
+    .expect("fixture league")
+}
+
+/// Signing key used for every token issued in tests
+const TEST_JWT_SECRET: &str = "test-secret";
+
+static INIT_ENV: Once = Once::new();
+
+/// Sets the environment the auth code reads, once per test binary
+pub fn init_env() {
+    INIT_ENV.call_once(|| std::env::set_var("JWT_SECRET", TEST_JWT_SECRET));
+}
+
+/// Builds a local client for the full application backed by `pool`. MongoDB is
+/// never contacted unless a test hits a pro player route.
+pub async fn client(pool: PgPool) -> Client {
+    init_env();
+    let mongo_db = mongodb::Client::with_uri_str("mongodb://localhost:27017")
+        .await
+        .expect("mongo client")
+        .database("efantasy_test");
+    let state = AppState {
+        db: pool,
+        supabase_client: reqwest::Client::new(),
+        supabase_api_key: String::new(),
+        mongo_db,
+        word_filter: WordFilter::default(),
+        feed_cache: FeedCache::default(),
+    };
+    Client::tracked(crate::build_rocket(state)).await.expect("valid rocket instance")
+}
+
+/// Logs `username` in with `PASSWORD` and returns the bearer token
+pub async fn login(client: &Client, username: &str) -> String {
+    let response = client
+        .post("/login")
+        .json(&serde_json::json!({ "username": username, "password": PASSWORD }))
+        .dispatch()
+        .await;
+    assert_eq!(response.status(), Status::Ok);
+    response.into_json::<String>().await.expect("token")
+}
+
+/// The Authorization header for a bearer token
+pub fn bearer(token: &str) -> Header<'static> {
+    Header::new("Authorization", format!("Bearer {}", token))
+}
\ No newline at end of file
@@ -0,0 +1,281 @@
+use crate::errors::UserError;
+
+/// Minimum number of characters required of every password
+const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Minimum number of character classes (lowercase, uppercase, digit, symbol) required
+/// by the strict password policy
+const MIN_CHARACTER_CLASSES: usize = 3;
+
+/// Small embedded list of passwords that are too common to allow
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456", "12345678", "123456789", "1234567890", "111111", "000000",
+    "password", "password1", "password123", "passw0rd", "p@ssw0rd", "p@ssword1",
+    "qwerty", "qwerty123", "qwertyuiop", "abc123", "abcd1234", "letmein", "welcome",
+    "welcome1", "iloveyou", "admin", "admin123", "monkey", "dragon", "football",
+    "baseball", "sunshine", "princess", "trustno1", "superman", "starwars",
+];
+
+/// Password policy applied when users set a password
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordPolicy {
+    /// Enforces the baseline, mixed character classes and rejects common passwords
+    Strict,
+    /// Only enforces the baseline (length, a letter and a digit), for tests and
+    /// local development
+    Relaxed,
+}
+
+impl PasswordPolicy {
+    /// Reads the policy from PASSWORD_POLICY ("strict" or "relaxed"), defaulting to strict
+    pub fn from_env() -> Self {
+        match std::env::var("PASSWORD_POLICY") {
+            Ok(value) if value.trim().eq_ignore_ascii_case("relaxed") => PasswordPolicy::Relaxed,
+            _ => PasswordPolicy::Strict,
+        }
+    }
+}
+
+/// Validates a password against the configured password policy
+pub fn validate_password_strength(password: &str) -> Result<(), UserError> {
+    validate_password_with_policy(password, PasswordPolicy::from_env())
+}
+
+/// Validates a password against the given policy
+pub fn validate_password_with_policy(password: &str, policy: PasswordPolicy) -> Result<(), UserError> {
+    if password.is_empty() {
+        return Err(UserError::WeakPassword("Password must not be empty".to_string()));
+    }
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(UserError::WeakPassword(format!(
+            "Password must be at least {} characters long",
+            MIN_PASSWORD_LENGTH
+        )));
+    }
+    if !password.chars().any(|c| c.is_alphabetic()) {
+        return Err(UserError::WeakPassword("Password must contain at least one letter".to_string()));
+    }
+    if !password.chars().any(|c| c.is_ascii_digit()) {
+        return Err(UserError::WeakPassword("Password must contain at least one digit".to_string()));
+    }
+    if policy == PasswordPolicy::Relaxed {
+        return Ok(());
+    }
+
+    let lowered = password.to_lowercase();
+    if COMMON_PASSWORDS.contains(&lowered.as_str()) {
+        return Err(UserError::WeakPassword("Password is too common".to_string()));
+    }
+
+    let classes = [
+        password.chars().any(|c| c.is_lowercase()),
+        password.chars().any(|c| c.is_uppercase()),
+        password.chars().any(|c| c.is_ascii_digit()),
+        password.chars().any(|c| !c.is_alphanumeric()),
+    ];
+    if classes.iter().filter(|present| **present).count() < MIN_CHARACTER_CLASSES {
+        return Err(UserError::WeakPassword(format!(
+            "Password must contain at least {} of: lowercase letters, uppercase letters, digits, symbols",
+            MIN_CHARACTER_CLASSES
+        )));
+    }
+
+    Ok(())
+}
+
+/// Normalizes an email address for storage and comparison (trimmed and lowercased)
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Checks that an email address has the shape `local@domain.tld`: one `@`, a
+/// non-empty local part, dot-separated non-empty domain labels and an alphabetic
+/// top-level domain of at least two characters. No whitespace is allowed.
+pub fn is_valid_email(email: &str) -> bool {
+    if email.len() > 254 || email.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    let (local, domain) = match email.split_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    if local.is_empty() || domain.contains('@') {
+        return false;
+    }
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    if labels.len() < 2 || labels.iter().any(|label| label.is_empty()) {
+        return false;
+    }
+    let tld = labels[labels.len() - 1];
+    tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// Validates that a timezone is a known IANA name such as "America/New_York"
+pub fn validate_timezone(timezone: &str) -> Result<(), UserError> {
+    timezone
+        .parse::<chrono_tz::Tz>()
+        .map(|_| ())
+        .map_err(|_| UserError::ValidationError(format!("Unknown timezone: {}", timezone)))
+}
+
+/// Case-insensitive filter for banned words in user-facing names
+/// (usernames, nicknames, league and team names)
+#[derive(Debug, Default)]
+pub struct WordFilter {
+    /// Each banned entry split into its lowercased words
+    banned: Vec<Vec<String>>,
+}
+
+impl WordFilter {
+    /// Creates a filter from a list of banned words
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let banned = words
+            .into_iter()
+            .map(|word| words_for_filter(word.as_ref()))
+            .filter(|words| !words.is_empty())
+            .collect();
+        WordFilter { banned }
+    }
+
+    /// Loads the word list from the file named by BANNED_WORDS_FILE, one word per line.
+    /// Blank lines and lines starting with `#` are ignored. If the variable is unset
+    /// the filter is empty and allows everything.
+    pub fn from_env() -> Result<Self, std::io::Error> {
+        let path = match std::env::var("BANNED_WORDS_FILE") {
+            Ok(path) => path,
+            Err(_) => {
+                println!("BANNED_WORDS_FILE not set, word filter is disabled");
+                return Ok(WordFilter::default());
+            }
+        };
+
+        let contents = std::fs::read_to_string(&path)?;
+        let filter = WordFilter::new(
+            contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.starts_with('#')),
+        );
+        println!("Loaded {} banned words from {}", filter.banned.len(), path);
+        Ok(filter)
+    }
+
+    /// Returns true if the text contains any banned word as a whole word, ignoring case.
+    /// Words are separated by anything that isn't a letter or digit, so "classic"
+    /// doesn't match a banned "ass" but "ass_99" does. Banned phrases must appear
+    /// as consecutive words.
+    pub fn contains_banned_word(&self, text: &str) -> bool {
+        let words = words_for_filter(text);
+        self.banned
+            .iter()
+            .any(|banned| words.windows(banned.len()).any(|window| window == banned.as_slice()))
+    }
+}
+
+/// Lowercases text and splits it into words of letters and digits
+fn words_for_filter(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_weak(password: &str, policy: PasswordPolicy) -> bool {
+        matches!(validate_password_with_policy(password, policy), Err(UserError::WeakPassword(_)))
+    }
+
+    #[test]
+    fn strict_policy_requires_three_character_classes() {
+        assert!(is_weak("abcdefg1", PasswordPolicy::Strict));
+        assert!(!is_weak("Abcdefg1", PasswordPolicy::Strict));
+        assert!(!is_weak("abcdefg1!", PasswordPolicy::Strict));
+    }
+
+    #[test]
+    fn strict_policy_rejects_common_passwords_in_any_case() {
+        assert!(is_weak("Password123", PasswordPolicy::Strict));
+        assert!(is_weak("P@ssw0rd", PasswordPolicy::Strict));
+    }
+
+    #[test]
+    fn relaxed_policy_only_enforces_the_baseline() {
+        assert!(!is_weak("abcdefg1", PasswordPolicy::Relaxed));
+        assert!(!is_weak("password123", PasswordPolicy::Relaxed));
+    }
+
+    #[test]
+    fn baseline_rules_apply_under_every_policy() {
+        for policy in [PasswordPolicy::Strict, PasswordPolicy::Relaxed] {
+            assert!(is_weak("", policy));
+            assert!(is_weak("Ab1!", policy));
+            assert!(is_weak("12345678!", policy));
+            assert!(is_weak("Abcdefgh!", policy));
+        }
+    }
+
+    #[test]
+    fn length_counts_characters_not_bytes() {
+        assert!(is_weak("Äé1!ü", PasswordPolicy::Relaxed));
+        assert!(!is_weak("Äé1!üößç", PasswordPolicy::Relaxed));
+    }
+
+    #[test]
+    fn accepts_well_formed_emails() {
+        assert!(is_valid_email("player@example.com"));
+        assert!(is_valid_email("first.last+fantasy@mail.example.co"));
+    }
+
+    #[test]
+    fn rejects_malformed_emails() {
+        for email in ["", "player", "@example.com", "player@", "player@example", "player@@example.com",
+                      "player@example.c", "player@example.c0m", "player@.example.com", "play er@example.com"] {
+            assert!(!is_valid_email(email), "{} should be invalid", email);
+        }
+    }
+
+    #[test]
+    fn normalizes_emails_for_comparison() {
+        assert_eq!(normalize_email("  Player@Example.COM "), "player@example.com");
+    }
+
+    #[test]
+    fn validates_timezones_against_iana_names() {
+        assert!(validate_timezone("America/New_York").is_ok());
+        assert!(validate_timezone("UTC").is_ok());
+        assert!(matches!(validate_timezone("Mars/Olympus_Mons"), Err(UserError::ValidationError(_))));
+    }
+
+    #[test]
+    fn word_filter_matches_whole_words_only() {
+        let filter = WordFilter::new(["ass"]);
+        assert!(!filter.contains_banned_word("Classic League"));
+        assert!(!filter.contains_banned_word("Assassins"));
+        assert!(filter.contains_banned_word("ASS"));
+        assert!(filter.contains_banned_word("ass_99"));
+        assert!(filter.contains_banned_word("Kick.Ass.League"));
+    }
+
+    #[test]
+    fn word_filter_matches_phrases_as_consecutive_words() {
+        let filter = WordFilter::new(["Bad Phrase"]);
+        assert!(filter.contains_banned_word("a bad-phrase team"));
+        assert!(!filter.contains_banned_word("badphrase"));
+        assert!(!filter.contains_banned_word("bad team phrase"));
+    }
+
+    #[test]
+    fn empty_word_filter_allows_everything() {
+        let filter = WordFilter::new(["", "  "]);
+        assert!(!filter.contains_banned_word("Any League Name"));
+    }
+}
\ No newline at end of file